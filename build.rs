@@ -0,0 +1,73 @@
+//! Turns every `examples/YYYY/NN/<name>.txt` with a `<name>.answers` sidecar
+//! into one test per listed part, included by `tests/examples.rs`.
+
+use std::env;
+use std::fs::{read_dir, read_to_string, write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+fn main() {
+    println!("cargo:rerun-if-changed=examples");
+
+    let mut tests = String::new();
+    for (year, year_dir) in numbered_dirs::<u16>(Path::new("examples")) {
+        for (day, day_dir) in numbered_dirs::<u8>(&year_dir) {
+            tests.push_str(&day_tests(year, day, &day_dir));
+        }
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("example_tests.rs");
+    write(out, tests).unwrap();
+}
+
+/// The subdirectories of `dir` named by a number, sorted.
+fn numbered_dirs<N: FromStr + Ord>(dir: &Path) -> Vec<(N, PathBuf)> {
+    let mut dirs = read_dir(dir)
+        .map(|dir| dir.flatten().map(|entry| entry.path()).collect::<Vec<_>>())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|path| path.is_dir())
+        .filter_map(|path| {
+            let number = path.file_name()?.to_str()?.parse::<N>().ok()?;
+            Some((number, path))
+        })
+        .collect::<Vec<_>>();
+    dirs.sort_by(|a, b| a.0.cmp(&b.0));
+
+    dirs
+}
+
+fn day_tests(year: u16, day: u8, day_dir: &Path) -> String {
+    let mut tests = String::new();
+    let mut sidecars = read_dir(day_dir)
+        .unwrap()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "answers"))
+        .collect::<Vec<_>>();
+    sidecars.sort();
+
+    for sidecar in sidecars {
+        let name = sidecar.file_stem().unwrap().to_str().unwrap();
+        let ident = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>()
+            .to_lowercase();
+        let parts = read_to_string(&sidecar)
+            .unwrap()
+            .lines()
+            .filter(|line| !line.trim_start().starts_with('#'))
+            .filter_map(|line| line.split_once(':'))
+            .filter_map(|(part, _)| part.trim().strip_prefix("part")?.parse::<u8>().ok())
+            .collect::<Vec<_>>();
+
+        for part in parts {
+            tests.push_str(&format!(
+                "#[test]\nfn y{year}_day{day:02}_{ident}_part{part}() {{\n    check({year}, {day}, {name:?}, {part});\n}}\n\n"
+            ));
+        }
+    }
+
+    tests
+}
@@ -0,0 +1,148 @@
+//! Allocation tracking for the runner. With the `alloc-stats` feature a counting
+//! global allocator is installed; otherwise [`measure`] reports nothing.
+
+use serde::Serialize;
+
+/// Heap usage of one phase on the measuring thread.
+#[derive(Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// Highest live heap size above what was live when the phase started.
+    pub peak_bytes: u64,
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use super::AllocStats;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    /// Counts are per thread, so `aoc run all` workers don't see each other's allocations.
+    #[derive(Clone, Copy)]
+    struct Counters {
+        allocations: u64,
+        bytes: u64,
+        live: i64,
+        peak: i64,
+    }
+
+    thread_local! {
+        static COUNTERS: Cell<Counters> = const {
+            Cell::new(Counters { allocations: 0, bytes: 0, live: 0, peak: 0 })
+        };
+    }
+
+    fn update(f: impl FnOnce(&mut Counters)) {
+        // Ignore allocations made while the thread-local is being torn down.
+        let _ = COUNTERS.try_with(|counters| {
+            let mut c = counters.get();
+            f(&mut c);
+            counters.set(c);
+        });
+    }
+
+    fn grow(c: &mut Counters, size: usize) {
+        c.allocations += 1;
+        c.bytes += size as u64;
+        c.live += size as i64;
+        c.peak = c.peak.max(c.live);
+    }
+
+    struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            update(|c| grow(c, layout.size()));
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            update(|c| grow(c, layout.size()));
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            update(|c| c.live -= layout.size() as i64);
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            update(|c| {
+                c.live -= layout.size() as i64;
+                grow(c, new_size);
+            });
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: Counting = Counting;
+
+    pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+        let before = COUNTERS.with(Cell::get);
+        update(|c| c.peak = c.live);
+        let res = f();
+        let after = COUNTERS.with(Cell::get);
+        // Keep an enclosing measurement's peak intact.
+        update(|c| c.peak = c.peak.max(before.peak));
+
+        let stats = AllocStats {
+            allocations: after.allocations - before.allocations,
+            bytes: after.bytes - before.bytes,
+            peak_bytes: (after.peak - before.live).max(0) as u64,
+        };
+        (res, stats)
+    }
+}
+
+/// Runs `f`, returning its heap usage if the `alloc-stats` feature is enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let (res, stats) = counting::measure(f);
+        (res, Some(stats))
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), None)
+    }
+}
+
+/// Formats a byte count with a binary unit, e.g. `1.5 KiB`.
+pub fn bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{bytes} B"),
+        _ => format!("{value:.1} {}", UNITS[unit]),
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[test]
+fn measure_counts_allocations() {
+    let (v, stats) = measure(|| {
+        let v = vec![0u8; 1000];
+        drop(vec![0u8; 4000]);
+        v
+    });
+    let stats = stats.unwrap();
+
+    assert_eq!(v.len(), 1000);
+    assert_eq!(stats.allocations, 2);
+    assert_eq!(stats.bytes, 5000);
+    assert_eq!(stats.peak_bytes, 5000);
+}
+
+#[test]
+fn byte_units() {
+    assert_eq!(bytes(512), "512 B");
+    assert_eq!(bytes(1536), "1.5 KiB");
+    assert_eq!(bytes(3 * 1024 * 1024), "3.0 MiB");
+}
@@ -0,0 +1,76 @@
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, write};
+use std::path::{Path, PathBuf};
+
+/// One day's answers keyed by input hash and part.
+type DayAnswers = BTreeMap<String, BTreeMap<u8, String>>;
+
+/// Known-good answers keyed by year, day, input hash and part.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Answers(BTreeMap<u16, BTreeMap<u8, DayAnswers>>);
+
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.json")
+}
+
+/// Hex SHA-256 of an input, so answers stay tied to the input they were computed from.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+impl Answers {
+    /// Loads the answers at `path`, or an empty set if nothing was recorded yet.
+    pub fn load(path: &Path) -> Result<Answers> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let json = read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        write(path, json + "\n").with_context(|| format!("writing {}", path.display()))
+    }
+
+    pub fn get(&self, year: u16, day: u8, input_hash: &str, part: u8) -> Option<&str> {
+        self.0
+            .get(&year)?
+            .get(&day)?
+            .get(input_hash)?
+            .get(&part)
+            .map(String::as_str)
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, input_hash: &str, part: u8, answer: String) {
+        self.0
+            .entry(year)
+            .or_default()
+            .entry(day)
+            .or_default()
+            .entry(input_hash.to_string())
+            .or_default()
+            .insert(part, answer);
+    }
+}
+
+#[test]
+fn answers_round_trip() {
+    let mut answers = Answers::default();
+    let hash = input_hash("125 17");
+    answers.record(2024, 11, &hash, 1, "55312".to_string());
+
+    let json = serde_json::to_string(&answers).unwrap();
+    let answers: Answers = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(answers.get(2024, 11, &hash, 1), Some("55312"));
+    assert_eq!(answers.get(2024, 11, &hash, 2), None);
+    assert_eq!(answers.get(2024, 11, &input_hash("125 18"), 1), None);
+    assert_eq!(answers.get(2023, 11, &hash, 1), None);
+}
@@ -0,0 +1,250 @@
+use crate::registry::Day;
+use crate::runner::timed;
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use std::fs::read_to_string;
+use std::path::Path;
+use std::time::Duration;
+
+/// Summary of repeated timings, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: f64,
+    pub median_ns: f64,
+    pub mean_ns: f64,
+    pub std_dev_ns: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut samples = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        samples.sort_by(f64::total_cmp);
+
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n;
+        let mid = samples.len() / 2;
+        let median = if samples.len() % 2 == 0 {
+            (samples[mid - 1] + samples[mid]) / 2.0
+        } else {
+            samples[mid]
+        };
+
+        Stats {
+            min_ns: samples[0],
+            median_ns: median,
+            mean_ns: mean,
+            std_dev_ns: variance.sqrt(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseBench {
+    pub phase: String,
+    pub stats: Stats,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub runs: usize,
+    pub phases: Vec<PhaseBench>,
+}
+
+pub(crate) fn sample<T>(
+    warmup: usize,
+    runs: usize,
+    mut f: impl FnMut() -> Result<T>,
+) -> Result<Stats> {
+    for _ in 0..warmup {
+        f()?;
+    }
+    let samples = (0..runs)
+        .map(|_| {
+            let (res, time) = timed(&mut f);
+            res.map(|_| time)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Stats::from_samples(&samples))
+}
+
+/// Times the parse and both parts of `day` separately, `runs` times each after `warmup` untimed runs.
+pub fn bench_day(day: &Day, input: &str, warmup: usize, runs: usize) -> Result<DayBench> {
+    ensure!(runs > 0, "at least one run is needed");
+
+    let parse = sample(warmup, runs, || day.parse(input))?;
+    let parsed = day.parse(input)?;
+    let part1 = sample(warmup, runs, || day.part1(&parsed))?;
+    let part2 = sample(warmup, runs, || day.part2(&parsed))?;
+
+    Ok(DayBench {
+        year: day.year,
+        day: day.day,
+        runs,
+        phases: [("parse", parse), ("part1", part1), ("part2", part2)]
+            .into_iter()
+            .map(|(phase, stats)| PhaseBench {
+                phase: phase.to_string(),
+                stats,
+            })
+            .collect(),
+    })
+}
+
+pub(crate) fn ns(ns: f64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns.round() as u64))
+}
+
+pub fn print_table(benches: &[DayBench]) {
+    println!(
+        "{:>4} {:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Year", "Day", "Phase", "min", "median", "mean", "std dev"
+    );
+    benches.iter().for_each(|bench| {
+        bench.phases.iter().for_each(|phase| {
+            println!(
+                "{:>4} {:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}",
+                bench.year,
+                bench.day,
+                phase.phase,
+                ns(phase.stats.min_ns),
+                ns(phase.stats.median_ns),
+                ns(phase.stats.mean_ns),
+                ns(phase.stats.std_dev_ns),
+            )
+        })
+    });
+}
+
+pub fn load(path: &Path) -> Result<Vec<DayBench>> {
+    let json = read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    serde_json::from_str(&json).with_context(|| format!("parsing {}", path.display()))
+}
+
+/// A phase's median time against the baseline's.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub phase: String,
+    pub baseline_ns: Option<f64>,
+    pub current_ns: f64,
+    pub regressed: bool,
+}
+
+impl Comparison {
+    /// Relative change against the baseline, e.g. `0.5` for 50% slower.
+    pub fn change(&self) -> Option<f64> {
+        self.baseline_ns
+            .map(|baseline| self.current_ns / baseline - 1.0)
+    }
+}
+
+/// Compares medians, flagging phases that got slower by more than `threshold_pct` percent.
+pub fn compare(baseline: &[DayBench], current: &[DayBench], threshold_pct: f64) -> Vec<Comparison> {
+    current
+        .iter()
+        .flat_map(|bench| {
+            bench.phases.iter().map(|phase| {
+                let baseline_ns = baseline
+                    .iter()
+                    .filter(|b| (b.year, b.day) == (bench.year, bench.day))
+                    .flat_map(|b| &b.phases)
+                    .find(|p| p.phase == phase.phase)
+                    .map(|p| p.stats.median_ns);
+                let current_ns = phase.stats.median_ns;
+
+                Comparison {
+                    year: bench.year,
+                    day: bench.day,
+                    phase: phase.phase.clone(),
+                    baseline_ns,
+                    current_ns,
+                    regressed: baseline_ns
+                        .is_some_and(|b| current_ns > b * (1.0 + threshold_pct / 100.0)),
+                }
+            })
+        })
+        .collect()
+}
+
+pub fn print_comparison(comparisons: &[Comparison]) {
+    println!(
+        "{:>4} {:>3}  {:<6}  {:>10}  {:>10}  {:>8}  Status",
+        "Year", "Day", "Phase", "baseline", "median", "change"
+    );
+    comparisons.iter().for_each(|c| {
+        println!(
+            "{:>4} {:>3}  {:<6}  {:>10}  {:>10}  {:>8}  {}",
+            c.year,
+            c.day,
+            c.phase,
+            c.baseline_ns.map(ns).unwrap_or_else(|| "-".to_string()),
+            ns(c.current_ns),
+            c.change()
+                .map(|change| format!("{:+.1}%", change * 100.0))
+                .unwrap_or_else(|| "-".to_string()),
+            match (c.regressed, c.baseline_ns) {
+                (true, _) => "REGRESSED",
+                (false, Some(_)) => "ok",
+                (false, None) => "new",
+            }
+        )
+    });
+}
+
+#[test]
+fn stats_from_samples() {
+    let samples = [4, 1, 3, 2].map(Duration::from_nanos);
+    let stats = Stats::from_samples(&samples);
+
+    assert_eq!(stats.min_ns, 1.0);
+    assert_eq!(stats.median_ns, 2.5);
+    assert_eq!(stats.mean_ns, 2.5);
+    assert_eq!(stats.std_dev_ns, 1.25f64.sqrt());
+}
+
+#[test]
+fn compare_flags_regressions() {
+    let bench = |day, parse_ns, part1_ns| DayBench {
+        year: 2024,
+        day,
+        runs: 1,
+        phases: [("parse", parse_ns), ("part1", part1_ns)]
+            .into_iter()
+            .map(|(phase, ns)| PhaseBench {
+                phase: phase.to_string(),
+                stats: Stats::from_samples(&[Duration::from_nanos(ns)]),
+            })
+            .collect(),
+    };
+    let baseline = [bench(6, 100, 1000)];
+    let current = [bench(6, 105, 2000), bench(7, 10, 10)];
+
+    let comparisons = compare(&baseline, &current, 10.0);
+
+    assert_eq!(
+        comparisons
+            .iter()
+            .map(|c| (
+                c.day,
+                c.phase.as_str(),
+                c.regressed,
+                c.baseline_ns.is_some()
+            ))
+            .collect::<Vec<_>>(),
+        [
+            (6, "parse", false, true),
+            (6, "part1", true, true),
+            (7, "parse", false, false),
+            (7, "part1", false, false),
+        ]
+    );
+    assert_eq!(comparisons[1].change(), Some(1.0));
+}
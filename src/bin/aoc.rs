@@ -0,0 +1,515 @@
+use anyhow::*;
+use aoc::answers::{self, Answers};
+use aoc::bench::{self, bench_day};
+use aoc::compare::{self, compare_day};
+use aoc::fuzz::{self, fuzz_day, Outcome};
+use aoc::gen;
+use aoc::input;
+use aoc::recording::Recording;
+use aoc::registry;
+use aoc::report::{self, write_records, Format};
+use aoc::rng::Rng;
+use aoc::runner::{run_all, run_with_args, InputArgs, LogArgs, RunArgs};
+use aoc::scaffold;
+use aoc::simulation::Stop;
+use aoc::store;
+use aoc::verify::{print_table, verify, Status};
+use aoc::viewer;
+use aoc::watch;
+use clap::{Parser, Subcommand};
+use itertools::Itertools;
+use std::fs::write;
+use std::io::{stdout, IsTerminal, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::thread::available_parallelism;
+use std::time::{Duration, Instant};
+
+#[derive(Parser, Debug)]
+#[command(name = "aoc", about = "Advent of Code runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+/// A year or day number, or `all` of them.
+#[derive(Debug, Clone, Copy)]
+enum Target<T> {
+    All,
+    One(T),
+}
+
+impl<T> Target<T> {
+    fn one(self) -> Option<T> {
+        match self {
+            Target::All => None,
+            Target::One(one) => Some(one),
+        }
+    }
+}
+
+impl<T: FromStr> FromStr for Target<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "all" => Ok(Target::All),
+            one => Ok(Target::One(one.parse().ok().with_context(|| {
+                format!("expected a number or `all`, got {one:?}")
+            })?)),
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve a single day, every day of a year, or `all` years.
+    Run {
+        year: Target<u16>,
+        /// Defaults to `all` days of the year.
+        day: Option<Target<u8>>,
+        #[command(flatten)]
+        args: RunArgs,
+        /// Days to solve concurrently with several days; defaults to the number of CPUs.
+        #[arg(long)]
+        jobs: Option<usize>,
+    },
+    /// Solve every day and compare against the recorded answers.
+    Verify {
+        /// Store the computed answers, replacing any recorded ones.
+        #[arg(long)]
+        record: bool,
+        /// Answers file to read and update.
+        #[arg(long, default_value_os_t = answers::default_path())]
+        answers: PathBuf,
+        /// Seconds before a parse or part is given up on.
+        #[arg(long, default_value_t = 60.0)]
+        timeout: f64,
+    },
+    /// Time the parse and both parts of one or all days.
+    Bench {
+        /// Year to benchmark; every year if omitted.
+        year: Option<u16>,
+        /// Day to benchmark; every day of the year with an input if omitted.
+        day: Option<u8>,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Untimed runs before measuring.
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Timed runs per phase.
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// Also write the results to this JSON file, e.g. to save a baseline.
+        #[arg(long, alias = "save")]
+        json: Option<PathBuf>,
+        /// Baseline JSON from an earlier `--json` run to compare median times against.
+        #[arg(long)]
+        compare: Option<PathBuf>,
+        /// Percentage slowdown against the baseline that counts as a regression.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Run every registered implementation of a day's parts, failing if their answers disagree.
+    Compare {
+        year: u16,
+        day: u8,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Untimed runs before measuring.
+        #[arg(long, default_value_t = 1)]
+        warmup: usize,
+        /// Timed runs per implementation.
+        #[arg(long, default_value_t = 5)]
+        runs: usize,
+    },
+    /// Create a skeleton solution, example stub and registration for a new day.
+    New { year: u16, day: u8 },
+    /// Feed mutated examples into each day's parse and solve, recording crashes as examples.
+    Fuzz {
+        /// Year to fuzz; every year if omitted.
+        year: Option<u16>,
+        /// Day to fuzz; every day of the year if omitted.
+        day: Option<u8>,
+        /// Mutated inputs to try per day.
+        #[arg(long, default_value_t = 1000)]
+        iterations: usize,
+        /// Seed for reproducing a run; taken from the clock if omitted.
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Seconds before a case counts as timed out; a case still running at three times this hangs.
+        #[arg(long, default_value_t = 1.0)]
+        timeout: f64,
+        /// Report crashes without writing them to the examples corpus.
+        #[arg(long)]
+        no_record: bool,
+    },
+    /// Re-solve a day's examples and input whenever they change, rebuilding on source changes.
+    Watch {
+        year: u16,
+        day: u8,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Seconds before a parse or part is given up on.
+        #[arg(long, default_value_t = 10.0)]
+        timeout: f64,
+    },
+    /// Print a random but valid input for a day, for stress and scale testing.
+    Gen {
+        year: u16,
+        day: u8,
+        /// Map side length or number of records; defaults to about the real input's.
+        #[arg(long)]
+        size: Option<usize>,
+        /// Seed for reproducing an input; taken from the clock if omitted.
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Run a day's simulation until a breakpoint, printing the states leading up to it.
+    Debug {
+        year: u16,
+        day: u8,
+        #[arg(long, default_value_t = 1)]
+        part: u8,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Named condition to stop at, e.g. `revisit`; repeat for several.
+        #[arg(long = "break")]
+        breakpoints: Vec<String>,
+        /// Breakpoint hits to run past before stopping.
+        #[arg(long, default_value_t = 0)]
+        skip: usize,
+        /// Steps to give up after.
+        #[arg(long)]
+        limit: Option<usize>,
+        /// Earlier states to print before the one stopped at.
+        #[arg(long, default_value_t = 3)]
+        history: usize,
+        /// Print every state as it's reached.
+        #[arg(long)]
+        trace: bool,
+    },
+    /// Step through a day's simulation in the terminal, as it runs or from a saved recording.
+    View {
+        #[arg(required_unless_present = "load")]
+        year: Option<u16>,
+        #[arg(required_unless_present = "load")]
+        day: Option<u8>,
+        #[arg(long, default_value_t = 1)]
+        part: u8,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Save the recording to this file on quitting.
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// View a recording saved with `--save` instead of solving.
+        #[arg(long, conflicts_with_all = ["year", "day", "save"])]
+        load: Option<PathBuf>,
+    },
+    /// Inspect the stored puzzle inputs.
+    Inputs {
+        #[command(subcommand)]
+        command: InputsCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum InputsCommand {
+    /// List each day's input with its size, checksum and whether it changed.
+    Status,
+}
+
+fn main() -> Result<ExitCode> {
+    let cli = Cli::parse();
+    cli.log.init();
+
+    match cli.command {
+        Command::Run {
+            year,
+            day: Some(Target::One(day)),
+            args,
+            ..
+        } => {
+            let year = year.one().context("a day needs a year")?;
+            run_with_args(registry::find(year, day)?, &args)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Run {
+            year, args, jobs, ..
+        } => {
+            ensure!(args.input.input.is_none(), "--input needs a single day");
+            ensure!(!args.stream, "--stream needs a single day");
+            let jobs = match jobs {
+                Some(jobs) => jobs,
+                None => available_parallelism()?.get(),
+            };
+
+            let start = Instant::now();
+            let days = registry::select(year.one(), None)?;
+            let records = run_all(&days, jobs, args.timeout);
+            match args.format {
+                Format::Text => report::print_table(&records, start.elapsed()),
+                format => write_records(&mut stdout().lock(), &records, format)?,
+            }
+
+            Ok(if records.iter().any(|record| record.status.is_failure()) {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            })
+        }
+        Command::Verify {
+            record,
+            answers,
+            timeout,
+        } => {
+            let timeout = Duration::try_from_secs_f64(timeout)?;
+            let mut recorded = Answers::load(&answers)?;
+            let checks = verify(&mut recorded, record, timeout);
+            print_table(&checks);
+            if record {
+                recorded.save(&answers)?;
+            }
+
+            let failed = checks
+                .iter()
+                .any(|check| matches!(check.status, Status::Fail | Status::Error));
+            Ok(if failed {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            })
+        }
+        Command::Bench {
+            year,
+            day,
+            input,
+            warmup,
+            runs,
+            json,
+            compare,
+            threshold,
+        } => {
+            let baseline = compare.as_deref().map(bench::load).transpose()?;
+            let days = registry::select(year, day)?;
+            let benches = match day {
+                Some(_) => {
+                    let day = days[0];
+                    let input =
+                        input::resolve(day.year, day.day, input.input.as_deref())?.read()?;
+                    vec![bench_day(day, &input, warmup, runs)?]
+                }
+                None => {
+                    ensure!(input.input.is_none(), "--input needs a day");
+                    let mut benches = Vec::new();
+                    for day in days {
+                        let Some(source) = input::resolve(day.year, day.day, None).ok() else {
+                            eprintln!("skipping {day}: no input");
+                            continue;
+                        };
+                        benches.push(bench_day(day, &source.read()?, warmup, runs)?);
+                    }
+                    benches
+                }
+            };
+            bench::print_table(&benches);
+            if let Some(json) = json {
+                write(&json, serde_json::to_string_pretty(&benches)? + "\n")
+                    .with_context(|| format!("writing {}", json.display()))?;
+            }
+            if let Some(baseline) = baseline {
+                let comparisons = bench::compare(&baseline, &benches, threshold);
+                println!();
+                bench::print_comparison(&comparisons);
+                if comparisons.iter().any(|c| c.regressed) {
+                    return Ok(ExitCode::FAILURE);
+                }
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Compare {
+            year,
+            day,
+            input,
+            warmup,
+            runs,
+        } => {
+            let day = registry::find(year, day)?;
+            let input = input::resolve(day.year, day.day, input.input.as_deref())?.read()?;
+            let outcomes = compare_day(day, &input, warmup, runs)?;
+            compare::print_table(&outcomes);
+
+            let disagreements = compare::disagreements(&outcomes);
+            ensure!(
+                disagreements.is_empty(),
+                "{day} implementations disagree on part(s) {}",
+                disagreements.iter().join(", ")
+            );
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::New { year, day } => {
+            for path in scaffold::new_day(&scaffold::default_root(), year, day)? {
+                println!("created {}", path.display());
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Fuzz {
+            year,
+            day,
+            iterations,
+            seed,
+            timeout,
+            no_record,
+        } => {
+            let seed = seed.unwrap_or_else(Rng::seed_from_time);
+            println!("seed {seed}");
+            let mut rng = Rng::new(seed);
+            let timeout = Duration::try_from_secs_f64(timeout)?;
+            let days = registry::select(year, day)?;
+
+            let mut crashed = false;
+            for day in days {
+                let findings = fuzz_day(day, iterations, &mut rng, timeout)?;
+                println!("{day}: {} crash(es)", findings.len());
+                for finding in &findings {
+                    crashed = true;
+                    match &finding.outcome {
+                        Outcome::Panicked(message) => println!("  {message}"),
+                        outcome => println!("  {outcome:?}"),
+                    }
+                    println!("  input: {:?}", finding.input);
+                    if !no_record {
+                        let path =
+                            fuzz::record(day.year, day.day, &finding.input, &finding.outcome)?;
+                        println!("  recorded as {}", path.display());
+                    }
+                    if finding.outcome == Outcome::Hang {
+                        // The hung thread can't be stopped, so don't keep it running alongside more cases.
+                        return Ok(ExitCode::FAILURE);
+                    }
+                }
+            }
+
+            Ok(if crashed {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            })
+        }
+        Command::Watch {
+            year,
+            day,
+            input,
+            timeout,
+        } => {
+            let timeout = Duration::try_from_secs_f64(timeout)?;
+            watch::watch(registry::find(year, day)?, input.input.as_deref(), timeout)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Gen {
+            year,
+            day,
+            size,
+            seed,
+        } => {
+            let seed = seed.unwrap_or_else(Rng::seed_from_time);
+            eprintln!("seed {seed}");
+            let size = match size {
+                Some(size) => size,
+                None => gen::default_size(year, day)
+                    .with_context(|| format!("no generator for {year} day {day}"))?,
+            };
+            let input = gen::generate(year, day, size, &mut Rng::new(seed))?;
+            stdout().lock().write_all(input.as_bytes())?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Debug {
+            year,
+            day,
+            part,
+            input,
+            breakpoints,
+            skip,
+            limit,
+            history,
+            trace,
+        } => {
+            let day = registry::find(year, day)?;
+            ensure!(day.can_debug(), "{day} has no simulation to debug");
+            let input = input::resolve(year, day.day, input.input.as_deref())?.read()?;
+            let parsed = day.parse(&input)?;
+            let mut session = day.debug(&parsed, part, &breakpoints)?;
+            session.keep_history(history + 1);
+            if trace {
+                session.inspect(Box::new(|step, state| println!("step {step}: {state}")));
+            }
+
+            let mut hits = 0;
+            let stop = loop {
+                let remaining = limit.map(|limit| limit.saturating_sub(session.steps()));
+                match session.run(remaining)? {
+                    Stop::Breakpoint(_) if hits < skip => hits += 1,
+                    stop => break stop,
+                }
+            };
+            if !trace {
+                for (step, state) in session.history() {
+                    println!("step {step}: {state}");
+                }
+            }
+            let steps = session.steps();
+            match stop {
+                Stop::Breakpoint(name) => println!("stopped at {name} after {steps} steps"),
+                Stop::Done => println!("finished after {steps} steps"),
+                Stop::Limit => println!("gave up after {steps} steps"),
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::View {
+            year,
+            day,
+            part,
+            input,
+            save,
+            load,
+        } => {
+            ensure!(stdout().is_terminal(), "aoc view needs a terminal");
+            let recording = match (load, year, day) {
+                (Some(path), _, _) => viewer::view(Recording::load(&path)?, None)?,
+                (None, Some(year), Some(day)) => {
+                    let day = registry::find(year, day)?;
+                    let input =
+                        input::resolve(day.year, day.day, input.input.as_deref())?.read()?;
+                    let title = format!("{day} part {part}");
+                    viewer::view(Recording::new(&title), Some(viewer::live(day, part, input)))?
+                }
+                _ => bail!("a year and day are needed without --load"),
+            };
+            if let Some(path) = save {
+                recording.save(&path)?;
+                println!("saved {} frames to {}", recording.len(), path.display());
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Inputs {
+            command: InputsCommand::Status,
+        } => {
+            let entries = registry::days()
+                .filter_map(|day| store::entry(day.year, day.day).transpose())
+                .collect::<Result<Vec<_>>>()?;
+            store::print_status(&entries);
+
+            let changed = entries
+                .iter()
+                .any(|entry| matches!(entry.integrity, store::Integrity::Changed { .. }));
+            Ok(if changed {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            })
+        }
+    }
+}
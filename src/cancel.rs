@@ -0,0 +1,103 @@
+//! Cooperative cancellation. The runner installs a [`CancellationToken`] around
+//! each phase and solutions call [`check`] at loop boundaries.
+
+use anyhow::*;
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Returned by [`check`] once the current phase has been cancelled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cancelled {
+    TimedOut(Duration),
+    Requested,
+}
+
+impl Display for Cancelled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Cancelled::TimedOut(timeout) => write!(f, "timed out after {timeout:.2?}"),
+            Cancelled::Requested => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// Cancelled explicitly through any clone, or once its deadline passes.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<(Instant, Duration)>,
+}
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// A token that cancels itself `timeout` from now.
+    pub fn with_timeout(timeout: Duration) -> CancellationToken {
+        CancellationToken {
+            cancelled: Arc::default(),
+            deadline: Some((Instant::now() + timeout, timeout)),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return Err(Cancelled::Requested);
+        }
+        match self.deadline {
+            Some((deadline, timeout)) if Instant::now() >= deadline => {
+                Err(Cancelled::TimedOut(timeout))
+            }
+            _ => Result::Ok(()),
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `token` as the one [`check`] consults on this thread.
+pub fn scope<T>(token: &CancellationToken, f: impl FnOnce() -> T) -> T {
+    let previous = CURRENT.replace(Some(token.clone()));
+    let res = f();
+    CURRENT.set(previous);
+    res
+}
+
+/// Fails with [`Cancelled`] if the current phase was cancelled; a no-op outside the runner.
+pub fn check() -> Result<()> {
+    CURRENT.with(|current| match current.borrow().as_ref() {
+        Some(token) => token.check().map_err(Error::from),
+        None => Ok(()),
+    })
+}
+
+#[test]
+fn check_follows_the_scoped_token() {
+    assert!(check().is_ok());
+
+    let token = CancellationToken::new();
+    scope(&token, || {
+        assert!(check().is_ok());
+        token.clone().cancel();
+        assert_eq!(
+            check().unwrap_err().downcast_ref::<Cancelled>(),
+            Some(&Cancelled::Requested)
+        );
+    });
+    assert!(check().is_ok());
+
+    let token = CancellationToken::with_timeout(Duration::ZERO);
+    assert_eq!(token.check(), Err(Cancelled::TimedOut(Duration::ZERO)));
+}
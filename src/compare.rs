@@ -0,0 +1,109 @@
+//! `aoc compare`: runs every registered implementation of a day's parts on the
+//! same input, timing them and checking that they agree.
+
+use crate::bench::{ns, sample, Stats};
+use crate::registry::Day;
+use crate::runner::{catch, quiet_panics};
+use anyhow::*;
+
+/// One implementation's answer, and its timings if it succeeded.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub part: u8,
+    pub name: &'static str,
+    pub answer: Result<String, String>,
+    pub stats: Option<Stats>,
+}
+
+/// Parses `input` once, then solves it with each implementation, timing
+/// `runs` runs after `warmup` untimed ones. Outcomes are ordered by part,
+/// default implementation first.
+pub fn compare_day(day: &Day, input: &str, warmup: usize, runs: usize) -> Result<Vec<Outcome>> {
+    ensure!(runs > 0, "at least one run is needed");
+    let parsed = day.parse(input).context("parsing input")?;
+
+    let mut outcomes = quiet_panics(|| {
+        day.implementations()
+            .iter()
+            .map(|implementation| {
+                let answer = catch(|| implementation.solve(&parsed)).map_err(|e| format!("{e:#}"));
+                let stats = match answer {
+                    Result::Ok(_) => Some(sample(warmup, runs, || {
+                        catch(|| implementation.solve(&parsed))
+                    })?),
+                    Err(_) => None,
+                };
+                Ok(Outcome {
+                    part: implementation.part,
+                    name: implementation.name,
+                    answer,
+                    stats,
+                })
+            })
+            .collect::<Result<Vec<_>>>()
+    })?;
+    outcomes.sort_by_key(|outcome| outcome.part);
+
+    Ok(outcomes)
+}
+
+/// Parts whose implementations don't all give the same answer, counting a
+/// failed implementation as disagreeing. Expects outcomes ordered by part.
+pub fn disagreements(outcomes: &[Outcome]) -> Vec<u8> {
+    let mut parts = outcomes
+        .iter()
+        .map(|outcome| outcome.part)
+        .collect::<Vec<_>>();
+    parts.dedup();
+    parts.retain(|&part| {
+        let mut answers = outcomes
+            .iter()
+            .filter(|outcome| outcome.part == part)
+            .map(|outcome| outcome.answer.as_ref().ok());
+        let first = answers.next().flatten();
+        first.is_none() || answers.any(|answer| answer != first)
+    });
+    parts
+}
+
+pub fn print_table(outcomes: &[Outcome]) {
+    println!(
+        "{:>4}  {:<16}  {:>20}  {:>10}  {:>10}",
+        "Part", "Implementation", "Answer", "min", "median"
+    );
+    outcomes.iter().for_each(|outcome| {
+        let answer = match &outcome.answer {
+            Result::Ok(answer) => answer.clone(),
+            Err(e) => format!("error: {e}"),
+        };
+        let (min, median) = match outcome.stats {
+            Some(stats) => (ns(stats.min_ns), ns(stats.median_ns)),
+            None => ("-".to_string(), "-".to_string()),
+        };
+        println!(
+            "{:>4}  {:<16}  {:>20}  {:>10}  {:>10}",
+            outcome.part, outcome.name, answer, min, median
+        );
+    });
+}
+
+#[test]
+fn failures_and_mismatches_disagree() {
+    let outcome = |part, name, answer: Result<&str, &str>| Outcome {
+        part,
+        name,
+        answer: answer.map(str::to_string).map_err(str::to_string),
+        stats: None,
+    };
+    let outcomes = [
+        outcome(1, "default", Result::Ok("7")),
+        outcome(1, "fast", Result::Ok("7")),
+        outcome(2, "default", Result::Ok("9")),
+        outcome(2, "fast", Result::Ok("8")),
+    ];
+    assert_eq!(disagreements(&outcomes), vec![2]);
+    assert_eq!(
+        disagreements(&[outcome(1, "default", Err("boom"))]),
+        vec![1]
+    );
+}
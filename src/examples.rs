@@ -0,0 +1,95 @@
+use anyhow::*;
+use std::collections::BTreeMap;
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+
+/// A puzzle example from `examples/YYYY/NN/<name>.txt`, with the answers listed in
+/// the `<name>.answers` sidecar as `partN: answer` lines; `#` starts a comment line.
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub input: String,
+    pub answers: BTreeMap<u8, String>,
+}
+
+/// An answer that isn't checked; the part only has to finish without panicking.
+pub const ANY_ANSWER: &str = "?";
+
+pub fn dir(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(year.to_string())
+        .join(format!("{day:02}"))
+}
+
+pub fn parse_answers(sidecar: &str) -> Result<BTreeMap<u8, String>> {
+    sidecar
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|line| {
+            let (part, answer) = line
+                .split_once(':')
+                .with_context(|| format!("expected `partN: answer`, got {line:?}"))?;
+            let part = part
+                .trim()
+                .strip_prefix("part")
+                .and_then(|part| part.parse::<u8>().ok())
+                .with_context(|| format!("unknown part in {line:?}"))?;
+            Ok((part, answer.trim().to_string()))
+        })
+        .collect()
+}
+
+impl Example {
+    pub fn load(year: u16, day: u8, name: &str) -> Result<Example> {
+        let dir = dir(year, day);
+        let input_path = dir.join(format!("{name}.txt"));
+        let answers_path = dir.join(format!("{name}.answers"));
+        let input = read_to_string(&input_path)
+            .with_context(|| format!("reading {}", input_path.display()))?;
+        let answers = read_to_string(&answers_path)
+            .with_context(|| format!("reading {}", answers_path.display()))?;
+
+        Ok(Example {
+            year,
+            day,
+            name: name.to_string(),
+            input,
+            answers: parse_answers(&answers)?,
+        })
+    }
+
+    /// All examples recorded for `day`, sorted by name.
+    pub fn load_day(year: u16, day: u8) -> Result<Vec<Example>> {
+        let dir = dir(year, day);
+        if !dir.is_dir() {
+            return Ok(Vec::new());
+        }
+        let mut names = read_dir(&dir)?
+            .map(|entry| Ok(entry?.path()))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+            .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+            .collect::<Vec<_>>();
+        names.sort();
+
+        names
+            .iter()
+            .map(|name| Example::load(year, day, name))
+            .collect()
+    }
+}
+
+#[test]
+fn answers_sidecar() {
+    let answers = parse_answers("part1: 143\n\n# part3: 1\npart2:  123 \n").unwrap();
+    assert_eq!(answers.len(), 2);
+    assert_eq!(answers[&1], "143");
+    assert_eq!(answers[&2], "123");
+
+    assert!(parse_answers("143").is_err());
+    assert!(parse_answers("answer: 143").is_err());
+}
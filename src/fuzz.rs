@@ -0,0 +1,249 @@
+//! Feeds mangled inputs into each day's parse and solve, looking for panics and
+//! hangs. [`target`] backs the cargo-fuzz targets in `fuzz/`; [`fuzz_day`] is the
+//! stable-Rust driver behind `aoc fuzz`.
+
+use crate::answers::input_hash;
+use crate::cancel::{self, CancellationToken};
+use crate::examples::{self, Example};
+use crate::registry::{self, Day};
+use crate::report::Status;
+use crate::rng::Rng;
+use crate::runner::{quiet_panics, solve_with, SolveOptions};
+use anyhow::*;
+use std::fs::{create_dir_all, write};
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Entry point for a cargo-fuzz target: parses and solves `data` as `day`'s
+/// input, letting any panic through to the fuzzer.
+pub fn target(year: u16, day: u8, data: &[u8]) {
+    let Some(input) = std::str::from_utf8(data).ok() else {
+        return;
+    };
+    let day = registry::find(year, day).unwrap();
+    let token = CancellationToken::with_timeout(Duration::from_secs(1));
+
+    cancel::scope(&token, || {
+        if let Result::Ok(parsed) = day.parse(input) {
+            let _ = day.part1(&parsed);
+            let _ = day.part2(&parsed);
+        }
+    });
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// Solved or failed cleanly, including cooperative timeouts.
+    Ok,
+    Panicked(String),
+    /// Still running well past the timeout, i.e. not checking for cancellation.
+    Hang,
+}
+
+/// Solves `input` on a separate thread so a hang can be detected and abandoned.
+pub fn run_case(day: &'static Day, input: &str, timeout: Duration) -> Outcome {
+    let (tx, rx) = mpsc::channel();
+    let input = input.to_string();
+    thread::spawn(move || {
+        let options = SolveOptions {
+            timeout: Some(timeout),
+            show_progress: false,
+        };
+        let _ = tx.send(solve_with(day, &input, options));
+    });
+
+    match rx.recv_timeout(timeout * 3 + Duration::from_secs(1)) {
+        Result::Ok(records) => records
+            .into_iter()
+            .find(|record| record.status == Status::Panicked)
+            .map(|record| Outcome::Panicked(record.error.unwrap_or_default()))
+            .unwrap_or(Outcome::Ok),
+        Err(_) => Outcome::Hang,
+    }
+}
+
+/// Characters worth inserting: those already in the seeds plus common separators.
+fn alphabet(seeds: &[String]) -> Vec<char> {
+    let mut alphabet = seeds
+        .iter()
+        .flat_map(|seed| seed.chars())
+        .chain("0123456789-+,:| \n".chars())
+        .collect::<Vec<_>>();
+    alphabet.sort();
+    alphabet.dedup();
+    alphabet
+}
+
+/// Applies a few random edits to `seed`.
+pub fn mutate(rng: &mut Rng, seed: &str, alphabet: &[char]) -> String {
+    let mut chars = seed.chars().collect::<Vec<_>>();
+
+    for _ in 0..rng.between(1, 4) {
+        let at = rng.below(chars.len() + 1);
+        match rng.below(6) {
+            0 if at < chars.len() => chars[at] = *rng.pick(alphabet),
+            1 => chars.insert(at, *rng.pick(alphabet)),
+            2 if at < chars.len() => {
+                let end = (at + rng.below(8) + 1).min(chars.len());
+                chars.drain(at..end);
+            }
+            3 => chars.truncate(at),
+            4 if at < chars.len() => {
+                let end = (at + rng.below(16) + 1).min(chars.len());
+                let copy = chars[at..end].to_vec();
+                chars.splice(at..at, copy);
+            }
+            _ => {
+                let digits = rng.below(20) + 1;
+                (0..digits).for_each(|_| chars.insert(at, *rng.pick(&['9', '0', '1'])));
+            }
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+/// Greedily removes lines, then characters, while `input` still panics at the
+/// same location as `message`.
+pub fn minimise(day: &'static Day, input: &str, message: &str, timeout: Duration) -> String {
+    let panics = |candidate: &str| {
+        matches!(
+            run_case(day, candidate, timeout),
+            Outcome::Panicked(m) if location(&m) == location(message)
+        )
+    };
+
+    let mut lines = input.split_inclusive('\n').collect::<Vec<_>>();
+    let mut i = 0;
+    while i < lines.len() {
+        let mut candidate = lines.clone();
+        candidate.remove(i);
+        if panics(&candidate.concat()) {
+            lines = candidate;
+        } else {
+            i += 1;
+        }
+    }
+
+    let mut chars = lines.concat().chars().collect::<Vec<_>>();
+    let mut chunk = chars.len().div_ceil(2).max(1);
+    while chunk > 0 {
+        let mut i = 0;
+        while i < chars.len() {
+            let mut candidate = chars.clone();
+            candidate.drain(i..(i + chunk).min(chars.len()));
+            if panics(&candidate.iter().collect::<String>()) {
+                chars = candidate;
+            } else {
+                i += chunk;
+            }
+        }
+        chunk /= 2;
+    }
+
+    chars.into_iter().collect()
+}
+
+/// Saves a crashing input as `examples/YYYY/NN/fuzz-<hash>.txt`, with a sidecar
+/// that only requires both parts to finish without panicking.
+pub fn record(year: u16, day: u8, input: &str, outcome: &Outcome) -> Result<PathBuf> {
+    let dir = examples::dir(year, day);
+    let name = format!("fuzz-{}", &input_hash(input)[..8]);
+    let cause = match outcome {
+        Outcome::Panicked(message) => message.lines().next().unwrap_or_default().to_string(),
+        Outcome::Hang => "hung without checking for cancellation".to_string(),
+        Outcome::Ok => "no crash".to_string(),
+    };
+    let answers = format!(
+        "# Found by `aoc fuzz`: {cause}\npart1: {any}\npart2: {any}\n",
+        any = examples::ANY_ANSWER
+    );
+
+    create_dir_all(&dir)?;
+    let path = dir.join(format!("{name}.txt"));
+    write(&path, input).with_context(|| format!("writing {}", path.display()))?;
+    write(dir.join(format!("{name}.answers")), answers)?;
+
+    Ok(path)
+}
+
+/// Where a panic happened, so one bug reached through many inputs is reported once.
+fn location(message: &str) -> &str {
+    message
+        .rsplit_once(" at ")
+        .map_or(message, |(_, location)| location)
+}
+
+/// A panicking or hanging input found by [`fuzz_day`].
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub year: u16,
+    pub day: u8,
+    pub input: String,
+    pub outcome: Outcome,
+}
+
+/// Tries `iterations` mutations of `day`'s examples, minimising the first panic
+/// found at each location.
+/// Stops at the first hang, since its thread can't be reclaimed.
+pub fn fuzz_day(
+    day: &'static Day,
+    iterations: usize,
+    rng: &mut Rng,
+    timeout: Duration,
+) -> Result<Vec<Finding>> {
+    let mut seeds = Example::load_day(day.year, day.day)?
+        .into_iter()
+        .map(|example| example.input)
+        .collect::<Vec<_>>();
+    if seeds.is_empty() {
+        seeds.push(String::new());
+    }
+    let alphabet = alphabet(&seeds);
+    let mut findings: Vec<Finding> = Vec::new();
+
+    quiet_panics(|| {
+        for _ in 0..iterations {
+            let seed = rng.pick(&seeds);
+            let input = mutate(rng, seed, &alphabet);
+            match run_case(day, &input, timeout) {
+                Outcome::Ok => {}
+                Outcome::Panicked(message) => {
+                    let known = findings.iter().any(|f| {
+                        matches!(&f.outcome, Outcome::Panicked(m) if location(m) == location(&message))
+                    });
+                    if known {
+                        continue;
+                    }
+                    findings.push(Finding {
+                        year: day.year,
+                        day: day.day,
+                        input: minimise(day, &input, &message, timeout),
+                        outcome: Outcome::Panicked(message),
+                    });
+                }
+                Outcome::Hang => {
+                    findings.push(Finding {
+                        year: day.year,
+                        day: day.day,
+                        input,
+                        outcome: Outcome::Hang,
+                    });
+                    break;
+                }
+            }
+        }
+    });
+
+    Ok(findings)
+}
+
+#[test]
+fn mutate_is_reproducible() {
+    let alphabet = alphabet(&["1 2\n3 4\n".to_string()]);
+    let a = mutate(&mut Rng::new(7), "1 2\n3 4\n", &alphabet);
+    let b = mutate(&mut Rng::new(7), "1 2\n3 4\n", &alphabet);
+    assert_eq!(a, b);
+}
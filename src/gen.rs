@@ -0,0 +1,451 @@
+//! Seeded generators for random but valid puzzle inputs, for stress and scale
+//! testing beyond the one real input per day. `size` is a map's side length or
+//! the number of records in a list, see [`default_size`].
+
+use crate::rng::Rng;
+use anyhow::*;
+use itertools::Itertools;
+
+/// A size close to the real input's, e.g. 130 for 2024 day 6's 130×130 map.
+pub fn default_size(year: u16, day: u8) -> Option<usize> {
+    Some(match (year, day) {
+        (2024, 1 | 2 | 5) => 1000,
+        (2024, 14) => 400,
+        (2024, 3) => 700,
+        (2024, 4) => 140,
+        (2024, 6 | 12) => 130,
+        (2024, 7) => 850,
+        (2024, 8 | 10) => 50,
+        (2024, 9) => 19999,
+        (2024, 11) => 8,
+        (2024, 13) => 320,
+        (2024, 15) => 50,
+        _ => return None,
+    })
+}
+
+/// Generates an input for `day` that its solution should parse and solve.
+pub fn generate(year: u16, day: u8, size: usize, rng: &mut Rng) -> Result<String> {
+    ensure!(size > 0, "size must be positive");
+    Ok(match (year, day) {
+        (2024, 1) => location_lists(size, rng),
+        (2024, 2) => reports(size, rng),
+        (2024, 3) => corrupted_memory(size, rng),
+        (2024, 4) => word_search(size, rng),
+        (2024, 5) => page_orderings(size, rng),
+        (2024, 6) => guard_map(size, rng),
+        (2024, 7) => calibrations(size, rng),
+        (2024, 8) => antenna_map(size, rng),
+        (2024, 9) => disk_map(size, rng),
+        (2024, 10) => topographic_map(size, rng),
+        (2024, 11) => stones(size, rng),
+        (2024, 12) => garden_plots(size, rng),
+        (2024, 13) => claw_machines(size, rng),
+        (2024, 14) => robots(size, rng),
+        (2024, 15) => warehouse(size, rng),
+        _ => bail!("no generator for {year} day {day}"),
+    })
+}
+
+fn grid(size: usize, mut cell: impl FnMut(usize, usize) -> char) -> String {
+    (0..size)
+        .map(|y| (0..size).map(|x| cell(x, y)).collect::<String>() + "\n")
+        .collect()
+}
+
+/// `size` pairs of location ids, with the right list reusing some of the left's.
+pub fn location_lists(size: usize, rng: &mut Rng) -> String {
+    let left = (0..size)
+        .map(|_| rng.between(10000, 99999))
+        .collect::<Vec<_>>();
+    left.iter()
+        .map(|a| {
+            let b = match rng.chance(0.3) {
+                true => *rng.pick(&left),
+                false => rng.between(10000, 99999),
+            };
+            format!("{a}   {b}\n")
+        })
+        .collect()
+}
+
+/// `size` reports of 5 to 8 levels, some safe, some one bad level away and some neither.
+pub fn reports(size: usize, rng: &mut Rng) -> String {
+    (0..size)
+        .map(|_| {
+            let sign = *rng.pick(&[-1, 1]);
+            let mut level = rng.between(10, 90);
+            let mut levels = (0..rng.between(5, 8))
+                .map(|_| {
+                    level += sign * rng.between(1, 3);
+                    level
+                })
+                .collect::<Vec<_>>();
+            for _ in 0..rng.below(3) {
+                let at = rng.below(levels.len());
+                levels[at] = rng.between(1, 99);
+            }
+            levels.iter().join(" ") + "\n"
+        })
+        .collect()
+}
+
+/// Lines of junk hiding about `size` instructions, valid or nearly so.
+pub fn corrupted_memory(size: usize, rng: &mut Rng) -> String {
+    const JUNK: &[char] = &[
+        '%', '&', '!', '@', '^', '*', '(', ')', '[', ']', '<', '>', ',', '\'', ' ', '?', '+', '-',
+        '_', '/', ':', ';', '{', '}', 'w', 'h', 'o', 'y', 'x', 'm', 'u', 'l', 'd', 'n', 't',
+    ];
+    let mut memory = String::new();
+    for i in 0..size {
+        (0..rng.below(12)).for_each(|_| memory.push(*rng.pick(JUNK)));
+        let (a, b) = (rng.between(1, 999), rng.between(1, 999));
+        let instruction = match rng.below(10) {
+            0 => "do()".to_string(),
+            1 => "don't()".to_string(),
+            2 => format!("mul[{a},{b}]"),
+            3 => format!("mul({a},{b}"),
+            4 => format!("mul ( {a},{b})"),
+            _ => format!("mul({a},{b})"),
+        };
+        memory.push_str(&instruction);
+        if i % 120 == 119 {
+            memory.push('\n');
+        }
+    }
+    memory + "\n"
+}
+
+/// A `size`×`size` grid of X, M, A and S.
+pub fn word_search(size: usize, rng: &mut Rng) -> String {
+    grid(size, |_, _| *rng.pick(&['X', 'M', 'A', 'S']))
+}
+
+/// Rules totally ordering 49 pages, then `size` updates of an odd number of them.
+pub fn page_orderings(size: usize, rng: &mut Rng) -> String {
+    let mut pages = (10..100).collect::<Vec<i64>>();
+    shuffle(&mut pages, rng);
+    pages.truncate(49);
+
+    let mut rules = pages
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| format!("{a}|{b}\n"))
+        .collect::<Vec<_>>();
+    shuffle(&mut rules, rng);
+
+    let updates = (0..size).map(|_| {
+        let mut update = pages.clone();
+        shuffle(&mut update, rng);
+        update.truncate(rng.below(10) * 2 + 5);
+        if rng.chance(0.5) {
+            update.sort_by_key(|page| pages.iter().position(|p| p == page));
+        }
+        update.iter().join(",") + "\n"
+    });
+
+    rules.concat() + "\n" + &updates.collect::<String>()
+}
+
+/// A `size`×`size` lab whose obstructions turn the guard along a clockwise
+/// spiral out from near the middle, so it walks a good part of the map before
+/// leaving, like in the real input. Other obstructions are scattered off its path.
+pub fn guard_map(size: usize, rng: &mut Rng) -> String {
+    const DIRECTIONS: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+    let side = size as i64;
+    let inside = |x: i64, y: i64| (0..side).contains(&x) && (0..side).contains(&y);
+    let index = |x: i64, y: i64| (y * side + x) as usize;
+
+    let mut cells = vec!['.'; size * size];
+    let mut path = vec![false; size * size];
+    let (mut x, mut y) = (
+        (side / 2 + rng.between(-1, 1)).clamp(0, side - 1),
+        (side / 2 + rng.between(-1, 1)).clamp(0, side - 1),
+    );
+    cells[index(x, y)] = '^';
+
+    // Each ring is `spacing` cells further out than the last, clear of its obstructions.
+    let spacing = rng.between(2, 4);
+    for segment in 0.. {
+        let (dx, dy) = DIRECTIONS[segment % 4];
+        let length = spacing * (segment as i64 / 2 + 1);
+        for _ in 0..length {
+            path[index(x, y)] = true;
+            (x, y) = (x + dx, y + dy);
+            if !inside(x, y) {
+                return scatter(size, cells, &path, rng);
+            }
+        }
+        path[index(x, y)] = true;
+        if !inside(x + dx, y + dy) {
+            return scatter(size, cells, &path, rng);
+        }
+        cells[index(x + dx, y + dy)] = '#';
+    }
+    unreachable!("the spiral always leaves the map")
+}
+
+/// Adds obstructions to cells off the guard's `path`, which it never faces.
+fn scatter(size: usize, mut cells: Vec<char>, path: &[bool], rng: &mut Rng) -> String {
+    for (cell, &on_path) in cells.iter_mut().zip(path) {
+        if !on_path && *cell == '.' && rng.chance(0.04) {
+            *cell = '#';
+        }
+    }
+    grid(size, |x, y| cells[y * size + x])
+}
+
+/// `size` calibrations, about half of them solvable. Each has at most 18 digits
+/// in total, so no combination of operators overflows.
+pub fn calibrations(size: usize, rng: &mut Rng) -> String {
+    (0..size)
+        .map(|_| {
+            let mut digits = 18;
+            let mut parts = Vec::new();
+            while parts.len() < 12 && digits >= 3 {
+                let part = rng.between(1, 999) as u64;
+                digits -= part.ilog10() + 1;
+                parts.push(part);
+                if parts.len() >= 3 && rng.chance(0.2) {
+                    break;
+                }
+            }
+
+            let target = match rng.chance(0.5) {
+                true => parts[1..]
+                    .iter()
+                    .fold(parts[0], |acc, &part| match rng.below(3) {
+                        0 => acc + part,
+                        1 => acc * part,
+                        _ => acc * 10u64.pow(part.ilog10() + 1) + part,
+                    }),
+                false => rng.between(1, 1_000_000_000) as u64,
+            };
+            format!("{target}: {}\n", parts.iter().join(" "))
+        })
+        .collect()
+}
+
+/// A `size`×`size` map with a few antennas for each of up to 62 frequencies.
+pub fn antenna_map(size: usize, rng: &mut Rng) -> String {
+    let frequencies = ('0'..='9')
+        .chain('a'..='z')
+        .chain('A'..='Z')
+        .collect::<Vec<_>>();
+    let mut cells = vec!['.'; size * size];
+    let count = (size * size / 60).clamp(1, frequencies.len());
+    for &frequency in &frequencies[..count] {
+        for _ in 0..rng.between(2, 4) {
+            cells[rng.below(size * size)] = frequency;
+        }
+    }
+    grid(size, |x, y| cells[y * size + x])
+}
+
+/// A disk map of `size` digits, rounded up to an odd number so it ends with a file.
+pub fn disk_map(size: usize, rng: &mut Rng) -> String {
+    (0..size | 1)
+        .map(|i| match i % 2 {
+            0 => char::from(b'0' + rng.between(1, 9) as u8),
+            _ => char::from(b'0' + rng.between(0, 9) as u8),
+        })
+        .collect::<String>()
+        + "\n"
+}
+
+/// A `size`×`size` height map of random digits with hiking trails carved through it.
+pub fn topographic_map(size: usize, rng: &mut Rng) -> String {
+    const STEPS: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+    let mut heights = (0..size * size)
+        .map(|_| rng.between(0, 9))
+        .collect::<Vec<_>>();
+    let size = size as i64;
+
+    for _ in 0..size * size / 20 + 1 {
+        let (mut x, mut y) = (rng.between(0, size - 1), rng.between(0, size - 1));
+        heights[(y * size + x) as usize] = 0;
+        for height in 1..=9 {
+            let (dx, dy) = *rng.pick(&STEPS);
+            if (0..size).contains(&(x + dx)) && (0..size).contains(&(y + dy)) {
+                (x, y) = (x + dx, y + dy);
+            }
+            heights[(y * size + x) as usize] = height;
+        }
+    }
+    let size = size as usize;
+    grid(size, |x, y| char::from(b'0' + heights[y * size + x] as u8))
+}
+
+/// `size` engraved stones.
+pub fn stones(size: usize, rng: &mut Rng) -> String {
+    (0..size)
+        .map(|_| match rng.chance(0.1) {
+            true => 0,
+            false => rng.between(1, 9_999_999),
+        })
+        .join(" ")
+        + "\n"
+}
+
+/// A `size`×`size` garden where plots mostly continue a neighbouring region.
+pub fn garden_plots(size: usize, rng: &mut Rng) -> String {
+    let mut plots = vec!['A'; size * size];
+    for y in 0..size {
+        for x in 0..size {
+            let mut neighbours = Vec::new();
+            if x > 0 {
+                neighbours.push(plots[y * size + x - 1]);
+            }
+            if y > 0 {
+                neighbours.push(plots[(y - 1) * size + x]);
+            }
+            plots[y * size + x] = match neighbours.is_empty() || rng.chance(0.15) {
+                true => char::from(b'A' + rng.below(26) as u8),
+                false => *rng.pick(&neighbours),
+            };
+        }
+    }
+    grid(size, |x, y| plots[y * size + x])
+}
+
+/// `size` claw machines with non-parallel buttons; about half can be won
+/// within 100 presses of each.
+pub fn claw_machines(size: usize, rng: &mut Rng) -> String {
+    (0..size)
+        .map(|_| {
+            let (a, b) = loop {
+                let a = (rng.between(10, 99), rng.between(10, 99));
+                let b = (rng.between(10, 99), rng.between(10, 99));
+                if a.0 * b.1 != a.1 * b.0 {
+                    break (a, b);
+                }
+            };
+            let prize = match rng.chance(0.5) {
+                true => {
+                    let (pa, pb) = (rng.between(0, 100), rng.between(0, 100));
+                    (pa * a.0 + pb * b.0, pa * a.1 + pb * b.1)
+                }
+                false => (rng.between(1000, 20000), rng.between(1000, 20000)),
+            };
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            )
+        })
+        .join("\n")
+}
+
+/// `size` stray robots on the full-size map, plus a frame of robots that they
+/// all line up into at some second, so part 2 finishes.
+pub fn robots(size: usize, rng: &mut Rng) -> String {
+    use crate::y2024::day14::MAP_SIZE;
+    let (w, h) = (MAP_SIZE.x as i64, MAP_SIZE.y as i64);
+    let second = rng.between(1, w * h - 1);
+
+    let (width, height) = (rng.between(21, 31), rng.between(21, 31));
+    let (left, top) = (rng.between(0, w - width), rng.between(0, h - height));
+    let frame = (0..width)
+        .flat_map(|x| [(x, 0), (x, height - 1)])
+        .chain((1..height - 1).flat_map(|y| [(0, y), (width - 1, y)]))
+        .map(|(x, y)| (left + x, top + y));
+    let strays = (0..size)
+        .map(|_| (rng.between(0, w - 1), rng.between(0, h - 1)))
+        .collect::<Vec<_>>();
+
+    let mut robots = frame
+        .chain(strays)
+        .map(|(x, y)| {
+            let (vx, vy) = (rng.between(-w + 1, w - 1), rng.between(-h + 1, h - 1));
+            let start = (
+                (x - vx * second).rem_euclid(w),
+                (y - vy * second).rem_euclid(h),
+            );
+            format!("p={},{} v={vx},{vy}\n", start.0, start.1)
+        })
+        .collect::<Vec<_>>();
+    shuffle(&mut robots, rng);
+    robots.concat()
+}
+
+/// A walled `size`×`size` warehouse with crates, inner walls and one robot,
+/// followed by eight moves per cell, about the real input's ratio.
+pub fn warehouse(size: usize, rng: &mut Rng) -> String {
+    let size = size.max(3);
+    let mut cells = (0..size * size)
+        .map(|i| {
+            let (x, y) = (i % size, i / size);
+            if x == 0 || y == 0 || x == size - 1 || y == size - 1 || rng.chance(0.05) {
+                '#'
+            } else if rng.chance(0.3) {
+                'O'
+            } else {
+                '.'
+            }
+        })
+        .collect::<Vec<_>>();
+    let robot = (size + 1) + rng.below(size - 2) + rng.below(size - 2) * size;
+    cells[robot] = '@';
+
+    let moves = (0..size * size * 8)
+        .map(|_| *rng.pick(&['<', '>', '^', 'v']))
+        .chunks(70)
+        .into_iter()
+        .map(|line| line.collect::<String>() + "\n")
+        .collect::<String>();
+
+    grid(size, |x, y| cells[y * size + x]) + "\n" + &moves
+}
+
+/// Fisher–Yates, with the crate's own seeded PRNG.
+fn shuffle<T>(items: &mut [T], rng: &mut Rng) {
+    for i in (1..items.len()).rev() {
+        items.swap(i, rng.below(i + 1));
+    }
+}
+
+#[test]
+fn generated_inputs_solve() {
+    use crate::report::Status;
+    use crate::runner::{solve_with, SolveOptions};
+    use std::time::Duration;
+
+    let days = crate::registry::days().filter(|day| default_size(day.year, day.day).is_some());
+    for day in days {
+        let input = generate(day.year, day.day, 12, &mut Rng::new(u64::from(day.day))).unwrap();
+        let options = SolveOptions {
+            timeout: Some(Duration::from_secs(10)),
+            show_progress: false,
+        };
+        for record in solve_with(day, &input, options) {
+            assert_eq!(record.status, Status::Ok, "{day}: {:?}", record.error);
+        }
+    }
+}
+
+#[test]
+fn guard_maps_force_long_patrols() {
+    use crate::simulation::Simulation;
+    use crate::y2024::day06::{Day06, Patrol};
+    use crate::Solution;
+    use std::borrow::Cow;
+
+    let size = 40;
+    for seed in 0..10 {
+        let input = guard_map(size, &mut Rng::new(seed));
+        let (map_size, map) = Day06::parse(&input).unwrap();
+
+        let mut patrol = Patrol::new(Cow::Owned(map), map_size).unwrap();
+        while !patrol.done() {
+            assert!(
+                !patrol.revisiting(),
+                "seed {seed}: the guard loops\n{input}"
+            );
+            patrol.step().unwrap();
+        }
+        let visited = patrol.positions().len();
+        assert!(
+            visited >= size * size / 8,
+            "seed {seed}: the guard only visits {visited} cells\n{input}"
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use crate::store;
+use anyhow::*;
+use log::warn;
+use std::borrow::Cow;
+use std::env;
+use std::fs::{read_to_string, File};
+use std::io::{stdin, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming a directory that holds `YYYY/NN.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    /// An input found in an input directory, checked against that directory's checksums.
+    Stored {
+        day: u8,
+        path: PathBuf,
+    },
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                stdin()
+                    .read_to_string(&mut input)
+                    .context("reading stdin")?;
+                Ok(input)
+            }
+            InputSource::File(path) => {
+                read_to_string(path).with_context(|| format!("reading {}", path.display()))
+            }
+            InputSource::Stored { day, path } => store::read_verified(*day, path),
+        }
+    }
+
+    /// Opens the input for reading a line at a time. Unlike [`InputSource::read`],
+    /// a stored input's checksum isn't checked; see [`store::verify`].
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::Stdin => Ok(Box::new(stdin().lock())),
+            InputSource::File(path) | InputSource::Stored { path, .. } => {
+                let file =
+                    File::open(path).with_context(|| format!("opening {}", path.display()))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+}
+
+/// The text solutions parse, unless they ask for [`Solution::RAW_INPUT`]: no
+/// byte order mark, `\n` line endings, no trailing whitespace on any line, and
+/// nothing after the last non-blank line, not even a newline.
+///
+/// [`Solution::RAW_INPUT`]: crate::Solution::RAW_INPUT
+pub fn normalise(raw: &str) -> Cow<'_, str> {
+    let text = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+    let normal = !text.contains('\r')
+        && text
+            .split('\n')
+            .all(|line| line.len() == line.trim_end().len())
+        && text.len() == text.trim_end().len();
+    if normal {
+        return Cow::Borrowed(text);
+    }
+
+    let mut lines = text.lines().map(str::trim_end).collect::<Vec<_>>();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    Cow::Owned(lines.join("\n"))
+}
+
+pub fn file_name(day: u8) -> String {
+    format!("{day:02}.txt")
+}
+
+/// Where a day's input lives within an input directory. Each year has its own
+/// subdirectory, with its own checksums.
+pub fn relative_path(year: u16, day: u8) -> PathBuf {
+    Path::new(&year.to_string()).join(file_name(day))
+}
+
+/// The year whose inputs were kept directly in the input directory, as
+/// `NN.txt`, before each year had its own subdirectory.
+pub const FLAT_YEAR: u16 = 2024;
+
+/// Input directories in the order they are tried: the `AOC_INPUT_DIR`
+/// directory, `input/` in the working directory and each of its ancestors, and
+/// finally `input/` in this crate's root.
+fn input_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+        dirs.push(PathBuf::from(dir));
+    }
+    if let std::result::Result::Ok(cwd) = env::current_dir() {
+        cwd.ancestors().for_each(|dir| dirs.push(dir.join("input")));
+    }
+    let project_input = Path::new(env!("CARGO_MANIFEST_DIR")).join("input");
+    if !dirs.contains(&project_input) {
+        dirs.push(project_input);
+    }
+
+    dirs
+}
+
+/// Candidate locations for a day's input in `dirs`, in the order they are
+/// tried: [`relative_path`] in each, then for [`FLAT_YEAR`] the old flat layout.
+fn candidates_in(dirs: &[PathBuf], year: u16, day: u8) -> Vec<PathBuf> {
+    let mut relative_paths = vec![relative_path(year, day)];
+    if year == FLAT_YEAR {
+        relative_paths.push(PathBuf::from(file_name(day)));
+    }
+
+    relative_paths
+        .iter()
+        .flat_map(|relative_path| dirs.iter().map(move |dir| dir.join(relative_path)))
+        .collect()
+}
+
+fn candidates(year: u16, day: u8) -> Vec<PathBuf> {
+    candidates_in(&input_dirs(), year, day)
+}
+
+/// The first default location holding `day`'s input, warning if it's in the
+/// old flat layout.
+pub fn find(year: u16, day: u8) -> Option<PathBuf> {
+    let path = candidates(year, day)
+        .into_iter()
+        .find(|path| path.is_file())?;
+    if !path.ends_with(relative_path(year, day)) {
+        warn!(
+            "{} is in the old flat layout; move it and its checksum file into a {year}/ subdirectory",
+            path.display()
+        );
+    }
+    Some(path)
+}
+
+/// Works out where to read `day`'s input from. An explicit path wins, with `-`
+/// meaning stdin; otherwise the first existing default location is used.
+pub fn resolve(year: u16, day: u8, explicit: Option<&str>) -> Result<InputSource> {
+    match explicit {
+        Some("-") => Ok(InputSource::Stdin),
+        Some(path) => {
+            let path = PathBuf::from(path);
+            ensure!(path.is_file(), "input file {} not found", path.display());
+            Ok(InputSource::File(path))
+        }
+        None => match find(year, day) {
+            Some(path) => Ok(InputSource::Stored { day, path }),
+            None => {
+                let candidates = candidates(year, day);
+                bail!(
+                    "no input found for {year} day {day}, tried:\n{}",
+                    candidates
+                        .iter()
+                        .map(|path| format!("  {}", path.display()))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                )
+            }
+        },
+    }
+}
+
+#[test]
+fn explicit_path_must_exist() {
+    let err = resolve(2024, 1, Some("does/not/exist.txt")).unwrap_err();
+    assert!(err.to_string().contains("does/not/exist.txt"));
+}
+
+#[test]
+fn dash_reads_stdin() {
+    assert_eq!(resolve(2024, 1, Some("-")).unwrap(), InputSource::Stdin);
+}
+
+#[test]
+fn missing_input_names_all_candidates() {
+    let err = resolve(2024, 99, None).unwrap_err().to_string();
+    candidates(2024, 99).iter().for_each(|path| {
+        assert!(path.ends_with("99.txt"));
+        assert!(err.contains(&path.display().to_string()), "{err}");
+    });
+}
+
+#[test]
+fn flat_layout_is_tried_last_and_only_for_its_year() {
+    let dirs = [PathBuf::from("a"), PathBuf::from("b")];
+    assert_eq!(
+        candidates_in(&dirs, FLAT_YEAR, 1),
+        ["a/2024/01.txt", "b/2024/01.txt", "a/01.txt", "b/01.txt"].map(PathBuf::from)
+    );
+    assert_eq!(
+        candidates_in(&dirs, 2023, 1),
+        ["a/2023/01.txt", "b/2023/01.txt"].map(PathBuf::from)
+    );
+}
+
+#[test]
+fn normalise_cleans_up_line_endings() {
+    assert!(matches!(normalise("1 2\n3 4"), Cow::Borrowed("1 2\n3 4")));
+    assert_eq!(normalise("\u{feff}1 2 \r\n\r\n3 4\r\n\r\n"), "1 2\n\n3 4");
+    assert_eq!(normalise("2333133121414131402\n"), "2333133121414131402");
+    assert_eq!(normalise("\n \n"), "");
+}
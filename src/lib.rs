@@ -4,6 +4,50 @@ use nom::IResult;
 use std::collections::HashMap;
 use std::ops;
 
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod cancel;
+pub mod compare;
+pub mod examples;
+pub mod fuzz;
+pub mod gen;
+pub mod input;
+pub mod progress;
+pub mod recording;
+pub mod registry;
+pub mod report;
+pub mod rng;
+pub mod runner;
+pub mod scaffold;
+pub mod simulation;
+mod solution;
+pub mod store;
+pub mod stream;
+pub mod verify;
+pub mod viewer;
+pub mod watch;
+pub mod y2024;
+
+pub use solution::{Alternative, Solution};
+
+#[doc(hidden)]
+pub use log;
+
+/// Logs named intermediate values at debug level, e.g. `value!(seconds, area = w * h)`.
+#[macro_export]
+macro_rules! value {
+    (@one $name:ident = $value:expr) => {
+        $crate::log::debug!("{} = {:?}", stringify!($name), $value)
+    };
+    (@one $name:ident) => {
+        $crate::log::debug!("{} = {:?}", stringify!($name), $name)
+    };
+    ($($name:ident $(= $value:expr)?),+ $(,)?) => {
+        $($crate::value!(@one $name $(= $value)?);)+
+    };
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Coord {
     pub x: i32,
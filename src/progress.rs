@@ -0,0 +1,98 @@
+//! Progress reporting for long-running solutions. Solutions call [`update`] or
+//! [`tick`]; these do nothing unless the runner is rendering a bar.
+
+use std::cell::RefCell;
+use std::io::{stderr, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+/// Minimum time between redraws, so quick parts never draw at all.
+const REDRAW_EVERY: Duration = Duration::from_millis(100);
+const BAR_WIDTH: usize = 30;
+
+struct Bar {
+    label: String,
+    last_draw: Instant,
+    drawn: bool,
+    ticks: u64,
+}
+
+thread_local! {
+    static BAR: RefCell<Option<Bar>> = const { RefCell::new(None) };
+}
+
+fn with_bar(f: impl FnOnce(&mut Bar) -> Option<String>) {
+    BAR.with(|bar| {
+        let mut bar = bar.borrow_mut();
+        let Some(bar) = bar.as_mut() else {
+            return;
+        };
+        if let Some(text) = f(bar) {
+            if bar.last_draw.elapsed() >= REDRAW_EVERY {
+                eprint!("\r\x1b[2K{} {text}", bar.label);
+                let _ = stderr().flush();
+                bar.last_draw = Instant::now();
+                bar.drawn = true;
+            }
+        }
+    });
+}
+
+fn bar_text(done: u64, total: u64) -> String {
+    let fraction = if total == 0 {
+        1.0
+    } else {
+        (done as f64 / total as f64).min(1.0)
+    };
+    let filled = (fraction * BAR_WIDTH as f64) as usize;
+    format!(
+        "[{}{}] {:>3.0}% ({done}/{total})",
+        "#".repeat(filled),
+        ".".repeat(BAR_WIDTH - filled),
+        fraction * 100.0
+    )
+}
+
+/// Reports that `done` of `total` steps are finished.
+pub fn update(done: u64, total: u64) {
+    with_bar(|_| Some(bar_text(done, total)));
+}
+
+/// Reports one step of work whose total isn't known up front.
+pub fn tick() {
+    with_bar(|bar| {
+        bar.ticks += 1;
+        Some(format!("{} steps", bar.ticks))
+    });
+}
+
+/// Runs `f`, rendering its progress on stderr as `label` if `enabled` and stderr is a terminal.
+pub fn render<T>(label: &str, enabled: bool, f: impl FnOnce() -> T) -> T {
+    if !enabled || !stderr().is_terminal() {
+        return f();
+    }
+
+    let previous = BAR.replace(Some(Bar {
+        label: label.to_string(),
+        last_draw: Instant::now(),
+        drawn: false,
+        ticks: 0,
+    }));
+    let res = f();
+    if let Some(Bar { drawn: true, .. }) = BAR.replace(previous) {
+        eprint!("\r\x1b[2K");
+    }
+
+    res
+}
+
+#[test]
+fn bar_text_fills_proportionally() {
+    assert_eq!(
+        bar_text(1, 2),
+        format!("[{}{}]  50% (1/2)", "#".repeat(15), ".".repeat(15))
+    );
+    assert_eq!(
+        bar_text(0, 0),
+        format!("[{}] 100% (0/0)", "#".repeat(BAR_WIDTH))
+    );
+}
@@ -0,0 +1,294 @@
+//! Step-by-step recordings of grid simulations, for `aoc view`. Solutions call
+//! [`frame`] after each step; like [`progress`], it does nothing unless a
+//! recording is being made, so the grid is only rendered when it's wanted.
+//!
+//! [`progress`]: crate::progress
+
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs::{read_to_string, write};
+use std::path::Path;
+use std::sync::mpsc::SyncSender;
+
+/// Frames between full copies of the grid, bounding the work to rebuild any frame.
+const CHECKPOINT_EVERY: usize = 256;
+
+/// A rectangular grid of characters, padded with spaces where lines are short.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Grid {
+    pub width: usize,
+    pub height: usize,
+    cells: Vec<char>,
+    /// How many cells hold each character, kept up to date as cells change.
+    #[serde(skip)]
+    counts: HashMap<char, usize>,
+}
+
+impl Grid {
+    pub fn parse(text: &str) -> Grid {
+        let lines = text.lines().collect::<Vec<_>>();
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let cells = lines
+            .iter()
+            .flat_map(|line| {
+                let padding = width - line.chars().count();
+                line.chars().chain(std::iter::repeat_n(' ', padding))
+            })
+            .collect();
+
+        let mut grid = Grid {
+            width,
+            height: lines.len(),
+            cells,
+            counts: HashMap::new(),
+        };
+        grid.recount();
+        grid
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> char {
+        self.cells[y * self.width + x]
+    }
+
+    /// How many cells hold `c`.
+    pub fn count(&self, c: char) -> usize {
+        self.counts.get(&c).copied().unwrap_or(0)
+    }
+
+    fn set(&mut self, index: usize, c: char) {
+        let old = std::mem::replace(&mut self.cells[index], c);
+        *self.counts.entry(c).or_default() += 1;
+        if let Some(count) = self.counts.get_mut(&old) {
+            *count -= 1;
+            if *count == 0 {
+                self.counts.remove(&old);
+            }
+        }
+    }
+
+    fn recount(&mut self) {
+        self.counts.clear();
+        for &c in &self.cells {
+            *self.counts.entry(c).or_default() += 1;
+        }
+    }
+
+    /// Indices of the cells that differ from `other`'s, which must be the same size.
+    fn changes_from(&self, other: &Grid) -> Vec<(u32, char)> {
+        self.cells
+            .iter()
+            .zip(&other.cells)
+            .enumerate()
+            .filter(|(_, (new, old))| new != old)
+            .map(|(index, (&new, _))| (index as u32, new))
+            .collect()
+    }
+}
+
+/// How one frame differs from the one before.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Step {
+    caption: String,
+    /// The whole grid, when its size changed.
+    grid: Option<Grid>,
+    /// Changed cells by index, with their new contents.
+    changes: Vec<(u32, char)>,
+}
+
+/// The frames of a simulation, stored as differences from one frame to the next.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Recording {
+    pub title: String,
+    steps: Vec<Step>,
+    /// Every [`CHECKPOINT_EVERY`]th frame, rebuilt rather than saved.
+    #[serde(skip)]
+    checkpoints: Vec<Grid>,
+    #[serde(skip)]
+    last: Option<Grid>,
+}
+
+impl Recording {
+    pub fn new(title: &str) -> Recording {
+        Recording {
+            title: title.to_string(),
+            ..Recording::default()
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Recording> {
+        let json = read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        let saved: Recording =
+            serde_json::from_str(&json).with_context(|| format!("parsing {}", path.display()))?;
+
+        let mut recording = Recording::new(&saved.title);
+        saved
+            .steps
+            .into_iter()
+            .for_each(|step| recording.push_step(step));
+        Ok(recording)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        write(path, serde_json::to_string(self)?)
+            .with_context(|| format!("writing {}", path.display()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Adds a frame rendered as text.
+    pub fn push(&mut self, caption: String, text: &str) {
+        let grid = Grid::parse(text);
+        let step = match &self.last {
+            Some(last) if (last.width, last.height) == (grid.width, grid.height) => Step {
+                caption,
+                changes: grid.changes_from(last),
+                grid: None,
+            },
+            _ => Step {
+                caption,
+                changes: Vec::new(),
+                grid: Some(grid),
+            },
+        };
+        self.push_step(step);
+    }
+
+    fn push_step(&mut self, step: Step) {
+        let mut grid = self.last.take().unwrap_or_else(|| Grid::parse(""));
+        apply(&mut grid, &step);
+        if self.steps.len().is_multiple_of(CHECKPOINT_EVERY) {
+            self.checkpoints.push(grid.clone());
+        }
+        self.last = Some(grid);
+        self.steps.push(step);
+    }
+
+    /// Frame `index`, counting from 0.
+    pub fn frame(&self, index: usize) -> Grid {
+        let checkpoint = index / CHECKPOINT_EVERY;
+        let mut grid = self.checkpoints[checkpoint].clone();
+        self.steps[checkpoint * CHECKPOINT_EVERY + 1..=index]
+            .iter()
+            .for_each(|step| apply(&mut grid, step));
+        grid
+    }
+
+    /// Applies frame `index + 1`'s changes to `grid`, which must be frame `index`.
+    pub fn advance(&self, grid: &mut Grid, index: usize) {
+        apply(grid, &self.steps[index + 1]);
+    }
+
+    pub fn caption(&self, index: usize) -> &str {
+        &self.steps[index].caption
+    }
+
+    /// Indices of the cells frame `index` changed, or `None` if it replaced the whole grid.
+    pub fn changed(&self, index: usize) -> Option<impl Iterator<Item = usize> + '_> {
+        let step = &self.steps[index];
+        match (index, &step.grid) {
+            (0, _) | (_, Some(_)) => None,
+            _ => Some(step.changes.iter().map(|&(index, _)| index as usize)),
+        }
+    }
+}
+
+fn apply(grid: &mut Grid, step: &Step) {
+    if let Some(new) = &step.grid {
+        *grid = new.clone();
+        // Saved grids don't include their counts.
+        grid.recount();
+    }
+    step.changes
+        .iter()
+        .for_each(|&(index, c)| grid.set(index as usize, c));
+}
+
+/// Where [`frame`] sends frames: into a recording, or to a viewer as they're made.
+pub enum Sink {
+    Recording(Recording),
+    Live(SyncSender<(String, String)>),
+}
+
+thread_local! {
+    static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+/// Whether [`frame`] is recording on this thread, for solutions that need extra
+/// work to show their state.
+pub fn is_recording() -> bool {
+    SINK.with(|sink| sink.borrow().is_some())
+}
+
+/// Records a frame with a caption, e.g. the move just made. Neither closure is
+/// called unless a recording is being made.
+pub fn frame(caption: impl FnOnce() -> String, grid: impl FnOnce() -> String) {
+    SINK.with(|sink| match sink.borrow_mut().as_mut() {
+        None => {}
+        Some(Sink::Recording(recording)) => recording.push(caption(), &grid()),
+        // A viewer that has gone away just stops receiving frames.
+        Some(Sink::Live(tx)) => {
+            let _ = tx.send((caption(), grid()));
+        }
+    });
+}
+
+/// Runs `f` with its frames going to `sink`, and returns the sink afterwards.
+pub fn record<T>(sink: Sink, f: impl FnOnce() -> T) -> (T, Sink) {
+    let previous = SINK.replace(Some(sink));
+    let res = f();
+    let sink = SINK.replace(previous).unwrap();
+    (res, sink)
+}
+
+#[test]
+fn frames_are_rebuilt_from_changes() {
+    let frames = (0..600)
+        .map(|i| match i {
+            300 => "#####\n#...#\n".to_string(),
+            _ => format!("###\n#{}.\n", i % 10),
+        })
+        .collect::<Vec<_>>();
+    let ((), sink) = record(Sink::Recording(Recording::new("test")), || {
+        frames
+            .iter()
+            .enumerate()
+            .for_each(|(i, text)| frame(|| format!("step {i}"), || text.clone()))
+    });
+    let Sink::Recording(recording) = sink else {
+        unreachable!()
+    };
+
+    assert_eq!(recording.len(), 600);
+    for i in [0, 1, 255, 256, 299, 300, 301, 599] {
+        assert_eq!(recording.frame(i), Grid::parse(&frames[i]), "frame {i}");
+        assert_eq!(recording.caption(i), format!("step {i}"));
+    }
+    let mut grid = recording.frame(41);
+    recording.advance(&mut grid, 41);
+    assert_eq!(grid, recording.frame(42));
+    assert_eq!(
+        (grid.count('#'), grid.count('2'), grid.count('1')),
+        (4, 1, 0)
+    );
+    assert_eq!(recording.changed(42).unwrap().collect::<Vec<_>>(), [4]);
+    assert!(recording.changed(300).is_none());
+    assert!(!is_recording());
+
+    let path = std::env::temp_dir().join(format!("aoc-recording-{}.json", std::process::id()));
+    recording.save(&path).unwrap();
+    let loaded = Recording::load(&path).unwrap();
+    assert_eq!(loaded.frame(599), recording.frame(599));
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,277 @@
+use crate::input::normalise;
+use crate::simulation::{self, Debuggable, Session};
+use crate::solution::Alternative;
+use crate::stream::{self, RecordReader, Streaming};
+use crate::Solution;
+use anyhow::*;
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+
+/// A day's parsed input with its concrete type erased.
+pub type Parsed = Box<dyn Any>;
+
+/// A type-erased [`Solution`], so tooling can drive any day without knowing its types.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    parse: fn(&str) -> Result<Parsed>,
+    part1: fn(&Parsed) -> Result<String>,
+    part2: fn(&Parsed) -> Result<String>,
+    implementations: fn() -> Vec<Implementation>,
+    stream: Option<fn(&mut dyn BufRead) -> Result<Streamed>>,
+    debug: Option<StartDebugger>,
+}
+
+type StartDebugger = fn(&Parsed, u8, &[String]) -> Result<Box<dyn Session>>;
+
+/// Both answers from a streamed input, with the input's hash.
+#[derive(Debug, Clone)]
+pub struct Streamed {
+    pub answers: [String; 2],
+    pub input_hash: String,
+}
+
+fn stream<S: Streaming>(reader: &mut dyn BufRead) -> Result<Streamed> {
+    let mut records = RecordReader::new(reader, S::RECORDS);
+    let (answer1, answer2) = stream::solve::<S>(records.by_ref())?;
+    Ok(Streamed {
+        answers: [answer1.to_string(), answer2.to_string()],
+        input_hash: records.input_hash(),
+    })
+}
+
+fn debug<S: Debuggable>(
+    parsed: &Parsed,
+    part: u8,
+    breakpoints: &[String],
+) -> Result<Box<dyn Session>>
+where
+    S::Input: 'static,
+{
+    let debugger = simulation::debugger::<S>(downcast::<S>(parsed)?, part, breakpoints)?;
+    Ok(Box::new(debugger))
+}
+
+/// Name of the implementation behind [`Day::part1`] and [`Day::part2`].
+pub const DEFAULT_IMPLEMENTATION: &str = "default";
+
+type Solve = Box<dyn Fn(&Parsed) -> Result<String>>;
+
+/// One named way of solving one part of a day.
+pub struct Implementation {
+    pub name: &'static str,
+    pub part: u8,
+    solve: Solve,
+}
+
+impl Implementation {
+    pub fn solve(&self, parsed: &Parsed) -> Result<String> {
+        (self.solve)(parsed)
+    }
+}
+
+fn downcast<S: Solution>(parsed: &Parsed) -> Result<&S::Input>
+where
+    S::Input: 'static,
+{
+    parsed
+        .downcast_ref::<S::Input>()
+        .context("parsed input belongs to a different day")
+}
+
+fn parse<S: Solution>(input: &str) -> Result<Parsed>
+where
+    S::Input: 'static,
+{
+    match S::RAW_INPUT {
+        true => Ok(Box::new(S::parse(input)?)),
+        false => Ok(Box::new(S::parse(&normalise(input))?)),
+    }
+}
+
+fn part1<S: Solution>(parsed: &Parsed) -> Result<String>
+where
+    S::Input: 'static,
+{
+    Ok(S::part1(downcast::<S>(parsed)?)?.to_string())
+}
+
+fn part2<S: Solution>(parsed: &Parsed) -> Result<String>
+where
+    S::Input: 'static,
+{
+    Ok(S::part2(downcast::<S>(parsed)?)?.to_string())
+}
+
+fn implementations<S: Solution + 'static>() -> Vec<Implementation>
+where
+    S::Input: 'static,
+    S::Answer1: 'static,
+    S::Answer2: 'static,
+{
+    let default = [
+        Implementation {
+            name: DEFAULT_IMPLEMENTATION,
+            part: 1,
+            solve: Box::new(part1::<S>),
+        },
+        Implementation {
+            name: DEFAULT_IMPLEMENTATION,
+            part: 2,
+            solve: Box::new(part2::<S>),
+        },
+    ];
+    let alternatives = S::alternatives()
+        .into_iter()
+        .map(|alternative| match alternative {
+            Alternative::Part1(name, f) => Implementation {
+                name,
+                part: 1,
+                solve: Box::new(move |parsed| Ok(f(downcast::<S>(parsed)?)?.to_string())),
+            },
+            Alternative::Part2(name, f) => Implementation {
+                name,
+                part: 2,
+                solve: Box::new(move |parsed| Ok(f(downcast::<S>(parsed)?)?.to_string())),
+            },
+        });
+
+    default.into_iter().chain(alternatives).collect()
+}
+
+impl Day {
+    pub const fn new<S: Solution + 'static>(year: u16, day: u8) -> Day
+    where
+        S::Input: 'static,
+        S::Answer1: 'static,
+        S::Answer2: 'static,
+    {
+        Day {
+            year,
+            day,
+            parse: parse::<S>,
+            part1: part1::<S>,
+            part2: part2::<S>,
+            implementations: implementations::<S>,
+            stream: None,
+            debug: None,
+        }
+    }
+
+    /// Also lets the day be solved from a stream of records, see [`Streaming`].
+    pub const fn streaming<S: Streaming>(self) -> Day {
+        Day {
+            stream: Some(stream::<S>),
+            ..self
+        }
+    }
+
+    pub fn can_stream(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    /// Solves the input in `reader` a record at a time, without reading it whole.
+    pub fn stream(&self, reader: &mut dyn BufRead) -> Result<Streamed> {
+        match self.stream {
+            Some(stream) => stream(reader),
+            None => bail!("{self} can't be streamed"),
+        }
+    }
+
+    /// Also lets the day's parts be stepped through, see [`Debuggable`].
+    pub const fn debuggable<S: Debuggable>(self) -> Day
+    where
+        S::Input: 'static,
+    {
+        Day {
+            debug: Some(debug::<S>),
+            ..self
+        }
+    }
+
+    pub fn can_debug(&self) -> bool {
+        self.debug.is_some()
+    }
+
+    /// A debugger for part `part` of `parsed`, stopping at the named breakpoints.
+    pub fn debug(
+        &self,
+        parsed: &Parsed,
+        part: u8,
+        breakpoints: &[String],
+    ) -> Result<Box<dyn Session>> {
+        match self.debug {
+            Some(debug) => debug(parsed, part, breakpoints),
+            None => bail!("{self} has no simulation to debug"),
+        }
+    }
+
+    /// Parses `input`, [`normalise`]d unless the day wants it raw.
+    pub fn parse(&self, input: &str) -> Result<Parsed> {
+        (self.parse)(input)
+    }
+
+    pub fn part1(&self, parsed: &Parsed) -> Result<String> {
+        (self.part1)(parsed)
+    }
+
+    pub fn part2(&self, parsed: &Parsed) -> Result<String> {
+        (self.part2)(parsed)
+    }
+
+    /// Every registered implementation of both parts, the default ones first.
+    pub fn implementations(&self) -> Vec<Implementation> {
+        (self.implementations)()
+    }
+
+    /// Runs part `1` or `2` by number.
+    pub fn part(&self, part: u8, parsed: &Parsed) -> Result<String> {
+        match part {
+            1 => self.part1(parsed),
+            2 => self.part2(parsed),
+            _ => bail!("{self} has no part {part}"),
+        }
+    }
+}
+
+impl Display for Day {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+/// Every registered year's days, one slice per year in order.
+pub static YEARS: &[&[Day]] = &[
+    // One line per year, in order; `aoc new` adds new years here.
+    crate::y2024::DAYS,
+];
+
+/// Every registered day, ordered by year and day.
+pub fn days() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|days| days.iter())
+}
+
+pub fn find(year: u16, day: u8) -> Result<&'static Day> {
+    days()
+        .find(|d| d.year == year && d.day == day)
+        .with_context(|| format!("{year} day {day} is not registered"))
+}
+
+/// The days matching an optional year and day, e.g. for commands that default to all of them.
+pub fn select(year: Option<u16>, day: Option<u8>) -> Result<Vec<&'static Day>> {
+    match (year, day) {
+        (Some(year), Some(day)) => Ok(vec![find(year, day)?]),
+        (None, Some(_)) => bail!("a day needs a year"),
+        (year, None) => {
+            let days = days()
+                .filter(|d| year.is_none_or(|year| d.year == year))
+                .collect::<Vec<_>>();
+            if let Some(year) = year {
+                ensure!(!days.is_empty(), "no days are registered for {year}");
+            }
+            Ok(days)
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::alloc::{bytes, AllocStats};
+use anyhow::*;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::io::Write;
+use std::time::Duration;
+
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON object per line.
+    Json,
+    Csv,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Error,
+    Panicked,
+    #[serde(rename = "timed out")]
+    TimedOut,
+    #[serde(rename = "no input")]
+    NoInput,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Error => "error",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed out",
+            Status::NoInput => "no input",
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Error | Status::Panicked | Status::TimedOut)
+    }
+}
+
+/// The outcome of solving one part of one day.
+#[derive(Serialize, Debug, Clone)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub input_hash: String,
+    pub status: Status,
+    pub error: Option<String>,
+    /// Only recorded with the `alloc-stats` feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_alloc: Option<AllocStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solve_alloc: Option<AllocStats>,
+}
+
+const CSV_HEADER: &str = "year,day,part,answer,parse_ns,solve_ns,input_hash,status,error,\
+parse_allocs,parse_bytes,parse_peak_bytes,solve_allocs,solve_bytes,solve_peak_bytes";
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn opt<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(T::to_string).unwrap_or_default()
+}
+
+fn alloc_fields(stats: &Option<AllocStats>) -> [String; 3] {
+    [
+        opt(&stats.map(|s| s.allocations)),
+        opt(&stats.map(|s| s.bytes)),
+        opt(&stats.map(|s| s.peak_bytes)),
+    ]
+}
+
+fn alloc_summary(stats: &Option<AllocStats>) -> Option<String> {
+    stats.map(|s| {
+        format!(
+            "{} allocs, {}, peak {}",
+            s.allocations,
+            bytes(s.bytes),
+            bytes(s.peak_bytes)
+        )
+    })
+}
+
+impl Record {
+    fn to_csv(&self) -> String {
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            csv_field(&opt(&self.answer)),
+            opt(&self.parse_ns),
+            opt(&self.solve_ns),
+            self.input_hash.clone(),
+            self.status.as_str().to_string(),
+            csv_field(&opt(&self.error)),
+        ]
+        .into_iter()
+        .chain(alloc_fields(&self.parse_alloc))
+        .chain(alloc_fields(&self.solve_alloc))
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+
+    fn to_text(&self) -> String {
+        match &self.answer {
+            Some(answer) => {
+                let mut text = format!(
+                    "{} day {} part {}: {} (parse {}, solve {})",
+                    self.year,
+                    self.day,
+                    self.part,
+                    answer,
+                    duration(self.parse_ns),
+                    duration(self.solve_ns),
+                );
+                if let (Some(parse), Some(solve)) = (
+                    alloc_summary(&self.parse_alloc),
+                    alloc_summary(&self.solve_alloc),
+                ) {
+                    text.push_str(&format!(" [parse: {parse}; solve: {solve}]"));
+                }
+                text
+            }
+            None => format!(
+                "{} day {} part {}: {}: {}",
+                self.year,
+                self.day,
+                self.part,
+                self.status.as_str(),
+                self.error.as_deref().unwrap_or("unknown")
+            ),
+        }
+    }
+}
+
+/// Writes `records` in `format`; CSV gets a header line first.
+pub fn write_records(out: &mut impl Write, records: &[Record], format: Format) -> Result<()> {
+    if format == Format::Csv {
+        writeln!(out, "{CSV_HEADER}")?;
+    }
+    for record in records {
+        match format {
+            Format::Text => writeln!(out, "{}", record.to_text())?,
+            Format::Json => writeln!(out, "{}", serde_json::to_string(record)?)?,
+            Format::Csv => writeln!(out, "{}", record.to_csv())?,
+        }
+    }
+
+    Ok(())
+}
+
+fn duration(ns: Option<u64>) -> String {
+    ns.map(|ns| format!("{:.2?}", Duration::from_nanos(ns)))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Prints a summary table of `records` followed by the total wall time.
+pub fn print_table(records: &[Record], wall_time: Duration) {
+    println!(
+        "{:>4} {:>3} {:>4}  {:<20}  {:>10}  {:>10}  {:>10}  {:<9}  Error",
+        "Year", "Day", "Part", "Answer", "Parse", "Solve", "Peak mem", "Status"
+    );
+    records.iter().for_each(|record| {
+        let peak = record
+            .parse_alloc
+            .iter()
+            .chain(&record.solve_alloc)
+            .map(|s| s.peak_bytes)
+            .max();
+        println!(
+            "{:>4} {:>3} {:>4}  {:<20}  {:>10}  {:>10}  {:>10}  {:<9}  {}",
+            record.year,
+            record.day,
+            record.part,
+            record.answer.as_deref().unwrap_or("-"),
+            duration(record.parse_ns),
+            duration(record.solve_ns),
+            peak.map(bytes).unwrap_or_else(|| "-".to_string()),
+            record.status.as_str(),
+            record
+                .error
+                .as_deref()
+                .and_then(|e| e.lines().next())
+                .unwrap_or(""),
+        )
+    });
+    println!("Total wall time: {:.2?}", wall_time);
+}
+
+#[test]
+fn csv_escaping() {
+    let record = Record {
+        year: 2024,
+        day: 3,
+        part: 1,
+        answer: None,
+        parse_ns: Some(10),
+        solve_ns: None,
+        input_hash: "ab".to_string(),
+        status: Status::Error,
+        error: Some("bad \"input\", line 1".to_string()),
+        parse_alloc: Some(AllocStats {
+            allocations: 2,
+            bytes: 64,
+            peak_bytes: 48,
+        }),
+        solve_alloc: None,
+    };
+
+    assert_eq!(
+        record.to_csv(),
+        "2024,3,1,,10,,ab,error,\"bad \"\"input\"\", line 1\",2,64,48,,,"
+    );
+}
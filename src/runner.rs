@@ -0,0 +1,454 @@
+use crate::alloc::{self, AllocStats};
+use crate::answers::input_hash;
+use crate::cancel::{self, CancellationToken, Cancelled};
+use crate::input::{self, InputSource};
+use crate::progress;
+use crate::registry::{self, Day};
+use crate::report::{write_records, Format, Record, Status};
+use crate::store;
+use anyhow::*;
+use clap::{ArgAction, Args, Parser};
+use log::{debug, LevelFilter};
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::io::{stdout, BufRead};
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Args, Debug)]
+pub struct InputArgs {
+    /// Input file to solve, or `-` to read stdin. Defaults to `input/YYYY/NN.txt`.
+    #[arg(long)]
+    pub input: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub input: InputArgs,
+    /// Output format for the results.
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+    /// Give up on a parse or part that runs longer than this many seconds.
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    pub timeout: Option<Duration>,
+    /// Solve the input a record at a time instead of reading it whole, for days that support it.
+    #[arg(long)]
+    pub stream: bool,
+}
+
+fn parse_seconds(s: &str) -> Result<Duration> {
+    Ok(Duration::try_from_secs_f64(s.parse()?)?)
+}
+
+#[derive(Args, Debug)]
+pub struct LogArgs {
+    /// Log debug output and named values; repeat for trace-level state dumps.
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub verbose: u8,
+    /// Only log errors, e.g. to hide input checksum warnings.
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+}
+
+impl LogArgs {
+    /// Installs the logger. `RUST_LOG` directives apply on top of the `-v` level.
+    pub fn init(&self) {
+        let level = match self.verbose {
+            _ if self.quiet => LevelFilter::Error,
+            0 => LevelFilter::Warn,
+            1 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        };
+        env_logger::Builder::new()
+            .filter_level(level)
+            .parse_default_env()
+            .format_timestamp(None)
+            .init();
+    }
+}
+
+pub(crate) fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let res = f();
+    (res, start.elapsed())
+}
+
+/// A panic inside a solution, turned into an error.
+#[derive(Debug)]
+pub struct Panicked(pub String);
+
+impl Display for Panicked {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "panicked: {}", self.0)
+    }
+}
+
+impl std::error::Error for Panicked {}
+
+thread_local! {
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+fn record_panic(info: &PanicHookInfo) {
+    let message = info.payload_as_str().unwrap_or("Box<dyn Any>");
+    let message = match info.location() {
+        Some(location) => format!("{message} at {location}"),
+        None => message.to_string(),
+    };
+    LAST_PANIC.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Calls to [`quiet_panics`] in progress, on any thread.
+static QUIET: AtomicUsize = AtomicUsize::new(0);
+static INSTALL_HOOK: Once = Once::new();
+
+/// Runs `f` with panic messages captured for [`Panicked`] errors instead of printed.
+///
+/// The hook is installed once and defers to the previous one outside `f`, since
+/// a hook can't be swapped back while `f` is unwinding.
+pub(crate) fn quiet_panics<T>(f: impl FnOnce() -> T) -> T {
+    struct Quiet;
+
+    impl Drop for Quiet {
+        fn drop(&mut self) {
+            QUIET.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| match QUIET.load(Ordering::SeqCst) {
+            0 => previous(info),
+            _ => record_panic(info),
+        }));
+    });
+    QUIET.fetch_add(1, Ordering::SeqCst);
+    let _quiet = Quiet;
+    f()
+}
+
+/// Runs `f`, turning a panic into a [`Panicked`] error.
+pub(crate) fn catch<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = LAST_PANIC
+            .with(|last| last.borrow_mut().take())
+            .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Box<dyn Any>".to_string());
+        Err(Panicked(message).into())
+    })
+}
+
+fn record(
+    day: &Day,
+    part: u8,
+    input_hash: &str,
+    result: Result<String>,
+    parse: (Duration, Option<AllocStats>),
+    solve: Option<(Duration, Option<AllocStats>)>,
+) -> Record {
+    let (answer, error, status) = match result {
+        Result::Ok(answer) => (Some(answer), None, Status::Ok),
+        Err(e) if e.is::<Panicked>() => (None, Some(format!("{e:#}")), Status::Panicked),
+        Err(e) if e.is::<Cancelled>() => (None, Some(format!("{e:#}")), Status::TimedOut),
+        Err(e) => (None, Some(format!("{e:#}")), Status::Error),
+    };
+
+    Record {
+        year: day.year,
+        day: day.day,
+        part,
+        answer,
+        parse_ns: Some(parse.0.as_nanos() as u64),
+        solve_ns: solve.map(|(t, _)| t.as_nanos() as u64),
+        input_hash: input_hash.to_string(),
+        status,
+        error,
+        parse_alloc: parse.1,
+        solve_alloc: solve.and_then(|(_, a)| a),
+    }
+}
+
+/// How [`solve_with`] runs each phase.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SolveOptions {
+    /// Cancel a parse or part that runs longer than this.
+    pub timeout: Option<Duration>,
+    /// Render progress bars for the parts on a terminal.
+    pub show_progress: bool,
+}
+
+/// Times `f` under a fresh cancellation token, catching panics.
+fn phase<T>(
+    options: SolveOptions,
+    f: impl FnOnce() -> Result<T>,
+) -> ((Result<T>, Option<AllocStats>), Duration) {
+    let token = options
+        .timeout
+        .map(CancellationToken::with_timeout)
+        .unwrap_or_default();
+    timed(|| alloc::measure(|| cancel::scope(&token, || catch(f))))
+}
+
+/// Parses and solves `input`, timing each phase. Failures are captured in the records.
+pub fn solve(day: &Day, input: &str) -> Vec<Record> {
+    solve_with(day, input, SolveOptions::default())
+}
+
+pub fn solve_with(day: &Day, input: &str, options: SolveOptions) -> Vec<Record> {
+    let input_hash = input_hash(input);
+    debug!("{day}: solving {} bytes of input", input.len());
+
+    let ((parsed, parse_alloc), parse_time) = phase(options, || day.parse(input));
+    let parse = (parse_time, parse_alloc);
+    debug!("{day}: parsed in {parse_time:.2?}");
+    let parsed = match parsed {
+        Result::Ok(parsed) => parsed,
+        Err(e) => {
+            let error = || {
+                if let Some(Panicked(message)) = e.downcast_ref::<Panicked>() {
+                    Err(Panicked(message.clone()).into())
+                } else if let Some(cancelled) = e.downcast_ref::<Cancelled>() {
+                    Err(anyhow!(cancelled.clone()).context("parsing input"))
+                } else {
+                    Err(anyhow!("parsing input: {e:#}"))
+                }
+            };
+            return [1, 2]
+                .into_iter()
+                .map(|part| record(day, part, &input_hash, error(), parse, None))
+                .collect();
+        }
+    };
+
+    [1, 2]
+        .into_iter()
+        .map(|part| {
+            let label = format!("{day} part {part}");
+            let ((result, solve_alloc), solve_time) =
+                progress::render(&label, options.show_progress, || {
+                    phase(options, || day.part(part, &parsed))
+                });
+            let solve = (solve_time, solve_alloc);
+            debug!("{day} part {part}: solved in {solve_time:.2?}");
+            record(day, part, &input_hash, result, parse, Some(solve))
+        })
+        .collect()
+}
+
+/// A copy of `e` that [`record`] still classifies as a panic or timeout.
+fn duplicate(e: &Error) -> Error {
+    if let Some(Panicked(message)) = e.downcast_ref::<Panicked>() {
+        Panicked(message.clone()).into()
+    } else if let Some(cancelled) = e.downcast_ref::<Cancelled>() {
+        anyhow!(cancelled.clone())
+    } else {
+        anyhow!("{e:#}")
+    }
+}
+
+/// Solves the input in `reader` in a single pass, a record at a time. There
+/// is no separate parse, so both parts are given the whole pass's time.
+pub fn solve_streamed(day: &Day, reader: &mut dyn BufRead, options: SolveOptions) -> Vec<Record> {
+    let ((result, solve_alloc), solve_time) = phase(options, || day.stream(reader));
+    debug!("{day}: streamed in {solve_time:.2?}");
+    let solve = (solve_time, solve_alloc);
+    let input_hash = match &result {
+        Result::Ok(streamed) => streamed.input_hash.as_str(),
+        Err(_) => "",
+    };
+
+    [1, 2]
+        .into_iter()
+        .map(|part| {
+            let answer = match &result {
+                Result::Ok(streamed) => Ok(streamed.answers[part as usize - 1].clone()),
+                Err(e) => Err(duplicate(e)),
+            };
+            Record {
+                parse_ns: None,
+                ..record(
+                    day,
+                    part,
+                    input_hash,
+                    answer,
+                    (Duration::ZERO, None),
+                    Some(solve),
+                )
+            }
+        })
+        .collect()
+}
+
+/// Prints `records`, failing if any part failed.
+fn print(day: &Day, records: &[Record], format: Format) -> Result<()> {
+    write_records(&mut stdout().lock(), records, format)?;
+
+    match records.iter().find_map(|record| record.error.as_ref()) {
+        Some(error) => bail!("{day} failed: {error}"),
+        None => Ok(()),
+    }
+}
+
+/// Solves `input` and prints the results, failing if any part failed.
+pub fn run(day: &Day, input: &str, format: Format, timeout: Option<Duration>) -> Result<()> {
+    let options = SolveOptions {
+        timeout,
+        show_progress: true,
+    };
+    print(day, &solve_with(day, input, options), format)
+}
+
+/// Streams the input from `source` and prints the results, failing if any part failed.
+pub fn run_streamed(
+    day: &Day,
+    source: &InputSource,
+    format: Format,
+    timeout: Option<Duration>,
+) -> Result<()> {
+    ensure!(day.can_stream(), "{day} doesn't support --stream");
+    let options = SolveOptions {
+        timeout,
+        show_progress: false,
+    };
+    let records = solve_streamed(day, &mut source.open()?, options);
+    if let (InputSource::Stored { day, path }, Some(record)) = (source, records.first()) {
+        if !record.input_hash.is_empty() {
+            store::verify(*day, path, &record.input_hash)?;
+        }
+    }
+    print(day, &records, format)
+}
+
+fn no_input(day: &Day, error: &Error) -> Vec<Record> {
+    [1, 2]
+        .into_iter()
+        .map(|part| Record {
+            year: day.year,
+            day: day.day,
+            part,
+            answer: None,
+            parse_ns: None,
+            solve_ns: None,
+            input_hash: String::new(),
+            status: Status::NoInput,
+            error: Some(format!("{error:#}")),
+            parse_alloc: None,
+            solve_alloc: None,
+        })
+        .collect()
+}
+
+/// Solves every day in `days` on `jobs` worker threads. A panicking, failing or
+/// timed-out day is recorded and doesn't stop the others.
+pub fn run_all(days: &[&Day], jobs: usize, timeout: Option<Duration>) -> Vec<Record> {
+    let options = SolveOptions {
+        timeout,
+        show_progress: false,
+    };
+    let next = AtomicUsize::new(0);
+    let records = Mutex::new(Vec::new());
+
+    quiet_panics(|| {
+        thread::scope(|scope| {
+            (0..jobs.max(1)).for_each(|_| {
+                scope.spawn(|| {
+                    while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        let input = input::resolve(day.year, day.day, None).and_then(|s| s.read());
+                        let day_records = match input {
+                            Result::Ok(input) => solve_with(day, &input, options),
+                            Err(e) => no_input(day, &e),
+                        };
+                        records.lock().unwrap().extend(day_records);
+                    }
+                });
+            })
+        })
+    });
+
+    let mut records = records.into_inner().unwrap();
+    records.sort_by_key(|record| (record.year, record.day, record.part));
+    records
+}
+
+/// Resolves `day`'s input from the command line arguments and runs it.
+pub fn run_with_args(day: &Day, args: &RunArgs) -> Result<()> {
+    let source = input::resolve(day.year, day.day, args.input.input.as_deref())?;
+    if args.stream {
+        return run_streamed(day, &source, args.format, args.timeout);
+    }
+
+    run(day, &source.read()?, args.format, args.timeout)
+}
+
+/// Solves the day this binary is named for, taking the same options as `aoc run`.
+#[derive(Parser, Debug)]
+struct DayCli {
+    #[command(flatten)]
+    args: RunArgs,
+    #[command(flatten)]
+    log: LogArgs,
+}
+
+/// `aoc run YEAR DAY`, for the `src/bin/dayNN.rs` binaries.
+pub fn run_day(year: u16, day: u8) -> Result<()> {
+    let cli = DayCli::parse();
+    cli.log.init();
+    run_with_args(registry::find(year, day)?, &cli.args)
+}
+
+#[test]
+fn panics_become_errors() {
+    let error = catch::<()>(|| panic!("boom")).unwrap_err();
+    assert!(
+        matches!(error.downcast_ref::<Panicked>(), Some(Panicked(message)) if message.contains("boom"))
+    );
+}
+
+#[test]
+fn panics_are_printed_again_after_unwinding_out_of_quiet_panics() {
+    let _ = panic::catch_unwind(|| quiet_panics(|| panic!("escapes")));
+    assert_eq!(QUIET.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn timeouts_are_reported() {
+    use crate::Solution;
+
+    struct Forever;
+
+    impl Solution for Forever {
+        type Input = ();
+        type Answer1 = u8;
+        type Answer2 = u8;
+
+        fn parse(_input: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn part1(_input: &()) -> Result<u8> {
+            Ok(1)
+        }
+
+        fn part2(_input: &()) -> Result<u8> {
+            loop {
+                cancel::check()?;
+            }
+        }
+    }
+
+    let options = SolveOptions {
+        timeout: Some(Duration::from_millis(10)),
+        show_progress: false,
+    };
+    let records = solve_with(&Day::new::<Forever>(2024, 99), "", options);
+
+    assert_eq!(
+        records.iter().map(|r| r.status).collect::<Vec<_>>(),
+        [Status::Ok, Status::TimedOut]
+    );
+}
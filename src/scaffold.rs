@@ -0,0 +1,255 @@
+use crate::input::FLAT_YEAR;
+use anyhow::*;
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::{Path, PathBuf};
+
+/// The crate root, where `aoc new` writes its files.
+pub fn default_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn solution(day: u8) -> String {
+    format!(
+        r#"use crate::Solution;
+use anyhow::*;
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {{
+        Ok(input.lines().map(str::to_string).collect())
+    }}
+
+    fn part1(_input: &Self::Input) -> Result<u64> {{
+        bail!("part 1 is not solved yet")
+    }}
+
+    fn part2(_input: &Self::Input) -> Result<u64> {{
+        bail!("part 2 is not solved yet")
+    }}
+}}
+"#
+    )
+}
+
+/// A module for a year's first day, registering it.
+fn year_module(year: u16, day: u8) -> String {
+    format!(
+        r#"//! Advent of Code {year}.
+
+pub mod day{day:02};
+
+use crate::registry::Day;
+use day{day:02}::Day{day:02};
+
+pub static DAYS: &[Day] = &[
+    Day::new::<Day{day:02}>({year}, {day}),
+];
+"#
+    )
+}
+
+fn binary(year: u16, day: u8) -> String {
+    format!(
+        r#"fn main() -> anyhow::Result<()> {{
+    aoc::runner::run_day({year}, {day})
+}}
+"#
+    )
+}
+
+fn fuzz_target(year: u16, day: u8) -> String {
+    format!(
+        r#"#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| aoc::fuzz::target({year}, {day}, data));
+"#
+    )
+}
+
+fn fuzz_bin(year: u16, day: u8) -> String {
+    format!(
+        r#"
+[[bin]]
+name = "y{year}_day{day:02}"
+path = "fuzz_targets/y{year}/day{day:02}.rs"
+test = false
+doc = false
+bench = false
+"#
+    )
+}
+
+/// Commented out so the generated example tests stay quiet until the answers are filled in.
+const ANSWERS_STUB: &str = "# part1: \n# part2: \n";
+
+/// Inserts `line` among the lines matching `is_entry`, keeping them sorted.
+fn insert_sorted(source: &str, line: &str, is_entry: impl Fn(&str) -> bool) -> Result<String> {
+    let mut lines = source.lines().collect::<Vec<_>>();
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| is_entry(l))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    ensure!(!entries.is_empty(), "no place to insert {:?}", line.trim());
+    ensure!(
+        !entries.iter().any(|&i| lines[i] == line),
+        "{:?} is already present",
+        line.trim()
+    );
+
+    let at = entries
+        .iter()
+        .find(|&&i| lines[i] > line)
+        .copied()
+        .unwrap_or(entries[entries.len() - 1] + 1);
+    lines.insert(at, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Writes a skeleton for `day` of `year` under `root` and registers it,
+/// adding the year's module too if it's the year's first day. Creates nothing
+/// if any file exists.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>> {
+    ensure!(year >= 2015, "Advent of Code started in 2015, got {year}");
+    ensure!(
+        (1..=25).contains(&day),
+        "day must be between 1 and 25, got {day}"
+    );
+
+    let fuzz_manifest = root.join("fuzz/Cargo.toml");
+    let year_path = root.join(format!("src/y{year}.rs"));
+    let examples = root.join(format!("examples/{year}/{day:02}"));
+    let mut files = vec![
+        (
+            root.join(format!("src/y{year}/day{day:02}.rs")),
+            solution(day),
+        ),
+        (examples.join("example.txt"), String::new()),
+        (examples.join("example.answers"), ANSWERS_STUB.to_string()),
+    ];
+    // Only the flat year's days have `dayNN` binaries, from before there were years.
+    if year == FLAT_YEAR {
+        files.push((
+            root.join(format!("src/bin/day{day:02}.rs")),
+            binary(year, day),
+        ));
+    }
+    if fuzz_manifest.exists() {
+        files.push((
+            root.join(format!("fuzz/fuzz_targets/y{year}/day{day:02}.rs")),
+            fuzz_target(year, day),
+        ));
+    }
+    if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+        bail!("{} already exists", path.display());
+    }
+
+    // Registered either in the year's existing module, or by adding the year.
+    let mut edits = Vec::new();
+    if year_path.exists() {
+        let module = read_to_string(&year_path)
+            .with_context(|| format!("reading {}", year_path.display()))?;
+        let module = insert_sorted(&module, &format!("pub mod day{day:02};"), |l| {
+            l.starts_with("pub mod day")
+        })?;
+        let module = insert_sorted(&module, &format!("use day{day:02}::Day{day:02};"), |l| {
+            l.starts_with("use day")
+        })?;
+        let module = insert_sorted(
+            &module,
+            &format!("    Day::new::<Day{day:02}>({year}, {day}),"),
+            |l| l.starts_with("    Day::new::<Day"),
+        )?;
+        edits.push((year_path, module));
+    } else {
+        let lib_path = root.join("src/lib.rs");
+        let registry_path = root.join("src/registry.rs");
+        let lib =
+            read_to_string(&lib_path).with_context(|| format!("reading {}", lib_path.display()))?;
+        let registry = read_to_string(&registry_path)
+            .with_context(|| format!("reading {}", registry_path.display()))?;
+
+        let lib = insert_sorted(&lib, &format!("pub mod y{year};"), |l| {
+            l.starts_with("pub mod ")
+        })?;
+        let registry = insert_sorted(&registry, &format!("    crate::y{year}::DAYS,"), |l| {
+            l.starts_with("    crate::y")
+        })?;
+        edits.push((year_path, year_module(year, day)));
+        edits.push((lib_path, lib));
+        edits.push((registry_path, registry));
+    }
+
+    for (path, contents) in &files {
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+        write(path, contents).with_context(|| format!("writing {}", path.display()))?;
+    }
+    for (path, contents) in &edits {
+        write(path, contents).with_context(|| format!("writing {}", path.display()))?;
+    }
+    if fuzz_manifest.exists() {
+        let manifest = read_to_string(&fuzz_manifest)? + &fuzz_bin(year, day);
+        write(&fuzz_manifest, manifest)?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[test]
+fn new_day_registers_and_refuses_overwrite() {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    create_dir_all(root.join("src")).unwrap();
+    write(
+        root.join("src/lib.rs"),
+        "pub mod examples;\npub mod y2024;\n",
+    )
+    .unwrap();
+    write(
+        root.join("src/registry.rs"),
+        "pub static YEARS: &[&[Day]] = &[\n    crate::y2024::DAYS,\n];\n",
+    )
+    .unwrap();
+    write(
+        root.join("src/y2024.rs"),
+        "pub mod day01;\npub mod day03;\n\nuse crate::registry::Day;\nuse day01::Day01;\nuse day03::Day03;\n\npub static DAYS: &[Day] = &[\n    Day::new::<Day01>(2024, 1),\n    Day::new::<Day03>(2024, 3),\n];\n",
+    )
+    .unwrap();
+
+    let created = new_day(&root, 2024, 2).unwrap();
+
+    assert_eq!(created.len(), 4);
+    assert!(created.iter().all(|path| path.exists()));
+    assert_eq!(
+        read_to_string(root.join("src/y2024.rs")).unwrap(),
+        "pub mod day01;\npub mod day02;\npub mod day03;\n\nuse crate::registry::Day;\nuse day01::Day01;\nuse day02::Day02;\nuse day03::Day03;\n\npub static DAYS: &[Day] = &[\n    Day::new::<Day01>(2024, 1),\n    Day::new::<Day02>(2024, 2),\n    Day::new::<Day03>(2024, 3),\n];\n"
+    );
+    assert!(new_day(&root, 2024, 2).is_err());
+
+    new_day(&root, 2023, 5).unwrap();
+    assert!(!root.join("src/bin/day05.rs").exists());
+    assert_eq!(
+        read_to_string(root.join("src/lib.rs")).unwrap(),
+        "pub mod examples;\npub mod y2023;\npub mod y2024;\n"
+    );
+    assert_eq!(
+        read_to_string(root.join("src/registry.rs")).unwrap(),
+        "pub static YEARS: &[&[Day]] = &[\n    crate::y2023::DAYS,\n    crate::y2024::DAYS,\n];\n"
+    );
+    assert_eq!(
+        read_to_string(root.join("src/y2023.rs")).unwrap(),
+        year_module(2023, 5)
+    );
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
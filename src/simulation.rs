@@ -0,0 +1,274 @@
+//! Step-by-step simulations and a [`Debugger`] to drive them: it runs until a
+//! breakpoint's condition holds, calls back with each state and keeps the last
+//! few. Tests use [`Debugger`] directly; `aoc debug` reaches a day's through
+//! [`Debuggable`] and [`Session`].
+
+use crate::{cancel, Solution};
+use anyhow::*;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+
+/// States kept by a new [`Debugger`].
+pub const DEFAULT_HISTORY: usize = 64;
+
+/// Something that advances one step at a time until it's done, e.g. a guard's patrol.
+pub trait Simulation {
+    /// What a [`Debugger`] shows and keeps of each step.
+    type State: Clone + Debug;
+
+    fn state(&self) -> &Self::State;
+    fn step(&mut self) -> Result<()>;
+    fn done(&self) -> bool;
+}
+
+/// Why a [`Debugger`] stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stop {
+    /// The named breakpoint's condition held after a step.
+    Breakpoint(String),
+    Done,
+    /// The step limit was reached.
+    Limit,
+}
+
+struct Breakpoint<S> {
+    name: String,
+    condition: Box<dyn Fn(&S) -> bool>,
+}
+
+type Inspector<T> = Box<dyn FnMut(usize, &T)>;
+
+/// Runs a [`Simulation`] with conditional breakpoints, callbacks on every
+/// state and a bounded history.
+pub struct Debugger<S: Simulation> {
+    simulation: S,
+    steps: usize,
+    breakpoints: Vec<Breakpoint<S>>,
+    inspectors: Vec<Inspector<S::State>>,
+    /// The latest states with their step numbers, the current one last.
+    history: VecDeque<(usize, S::State)>,
+    capacity: usize,
+}
+
+impl<S: Simulation> Debugger<S> {
+    pub fn new(simulation: S) -> Debugger<S> {
+        let mut debugger = Debugger {
+            simulation,
+            steps: 0,
+            breakpoints: Vec::new(),
+            inspectors: Vec::new(),
+            history: VecDeque::new(),
+            capacity: DEFAULT_HISTORY,
+        };
+        debugger.remember();
+        debugger
+    }
+
+    /// Keeps only the latest `capacity` states, dropping older ones now if needed.
+    pub fn keep_history(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.history
+            .drain(..self.history.len().saturating_sub(capacity));
+    }
+
+    /// Stops after any step that leaves `condition` true of the simulation.
+    pub fn break_when(&mut self, name: &str, condition: impl Fn(&S) -> bool + 'static) {
+        self.breakpoints.push(Breakpoint {
+            name: name.to_string(),
+            condition: Box::new(condition),
+        });
+    }
+
+    /// Calls `f` with the step number and state after every step.
+    pub fn inspect(&mut self, f: impl FnMut(usize, &S::State) + 'static) {
+        self.inspectors.push(Box::new(f));
+    }
+
+    pub fn simulation(&self) -> &S {
+        &self.simulation
+    }
+
+    pub fn state(&self) -> &S::State {
+        self.simulation.state()
+    }
+
+    /// Steps taken so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// The kept states with their step numbers, oldest first.
+    pub fn history(&self) -> impl DoubleEndedIterator<Item = &(usize, S::State)> {
+        self.history.iter()
+    }
+
+    /// Takes one step, returning why to stop after it, if anything.
+    pub fn step(&mut self) -> Result<Option<Stop>> {
+        if self.simulation.done() {
+            return Ok(Some(Stop::Done));
+        }
+        self.simulation.step()?;
+        self.steps += 1;
+        self.remember();
+        let state = self.simulation.state();
+        self.inspectors
+            .iter_mut()
+            .for_each(|inspect| inspect(self.steps, state));
+
+        let hit = self
+            .breakpoints
+            .iter()
+            .find(|breakpoint| (breakpoint.condition)(&self.simulation));
+        Ok(match hit {
+            Some(breakpoint) => Some(Stop::Breakpoint(breakpoint.name.clone())),
+            None if self.simulation.done() => Some(Stop::Done),
+            None => None,
+        })
+    }
+
+    /// Steps until a breakpoint, the end, or `limit` steps, checking for cancellation.
+    pub fn run(&mut self, limit: Option<usize>) -> Result<Stop> {
+        for _ in 0..limit.unwrap_or(usize::MAX) {
+            cancel::check()?;
+            if let Some(stop) = self.step()? {
+                return Ok(stop);
+            }
+        }
+        Ok(Stop::Limit)
+    }
+
+    fn remember(&mut self) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.history.len() == self.capacity {
+            self.history.pop_front();
+        }
+        self.history
+            .push_back((self.steps, self.simulation.state().clone()));
+    }
+}
+
+/// A named breakpoint's condition, see [`Debuggable::breakpoints`].
+pub type Condition<S> = fn(&S) -> bool;
+
+/// A [`Solution`] whose parts can be stepped through by `aoc debug`.
+pub trait Debuggable: Solution {
+    type Simulation: Simulation + 'static;
+
+    /// The simulation behind part `part` of `input`.
+    fn simulation(input: &Self::Input, part: u8) -> Result<Self::Simulation>;
+
+    /// Named conditions `aoc debug --break` can stop on.
+    fn breakpoints() -> Vec<(&'static str, Condition<Self::Simulation>)>;
+}
+
+/// Sets up a [`Debugger`] for part `part` of `input` with the named breakpoints.
+pub fn debugger<S: Debuggable>(
+    input: &S::Input,
+    part: u8,
+    breakpoints: &[String],
+) -> Result<Debugger<S::Simulation>> {
+    let known = S::breakpoints();
+    let mut debugger = Debugger::new(S::simulation(input, part)?);
+    for name in breakpoints {
+        let Some(&(_, condition)) = known.iter().find(|(known, _)| known == name) else {
+            let names = known.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+            bail!("no breakpoint {name:?}, expected one of {names:?}");
+        };
+        debugger.break_when(name, condition);
+    }
+    Ok(debugger)
+}
+
+/// A [`Debugger`] with its simulation's type erased, showing states as text.
+pub trait Session {
+    fn keep_history(&mut self, capacity: usize);
+    fn inspect(&mut self, f: Box<dyn FnMut(usize, String)>);
+    fn run(&mut self, limit: Option<usize>) -> Result<Stop>;
+    fn steps(&self) -> usize;
+    fn state(&self) -> String;
+    fn history(&self) -> Vec<(usize, String)>;
+}
+
+impl<S: Simulation> Session for Debugger<S> {
+    fn keep_history(&mut self, capacity: usize) {
+        Debugger::keep_history(self, capacity)
+    }
+
+    fn inspect(&mut self, mut f: Box<dyn FnMut(usize, String)>) {
+        Debugger::inspect(self, move |step, state| f(step, format!("{state:?}")))
+    }
+
+    fn run(&mut self, limit: Option<usize>) -> Result<Stop> {
+        Debugger::run(self, limit)
+    }
+
+    fn steps(&self) -> usize {
+        self.steps
+    }
+
+    fn state(&self) -> String {
+        format!("{:?}", Debugger::state(self))
+    }
+
+    fn history(&self) -> Vec<(usize, String)> {
+        Debugger::history(self)
+            .map(|(step, state)| (*step, format!("{state:?}")))
+            .collect()
+    }
+}
+
+#[test]
+fn debugger_stops_at_breakpoints_and_keeps_recent_states() {
+    /// Counts up to 10.
+    struct Counter(u32);
+
+    impl Simulation for Counter {
+        type State = u32;
+
+        fn state(&self) -> &u32 {
+            &self.0
+        }
+
+        fn step(&mut self) -> Result<()> {
+            self.0 += 1;
+            Ok(())
+        }
+
+        fn done(&self) -> bool {
+            self.0 == 10
+        }
+    }
+
+    let seen = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let mut debugger = Debugger::new(Counter(0));
+    debugger.keep_history(3);
+    debugger.break_when("multiple of 4", |counter| counter.0.is_multiple_of(4));
+    debugger.inspect({
+        let seen = seen.clone();
+        move |step, &state| seen.borrow_mut().push((step, state))
+    });
+
+    assert_eq!(debugger.step().unwrap(), None);
+    assert_eq!(
+        debugger.run(None).unwrap(),
+        Stop::Breakpoint("multiple of 4".to_string())
+    );
+    assert_eq!(*debugger.state(), 4);
+    assert_eq!(debugger.run(Some(2)).unwrap(), Stop::Limit);
+    assert_eq!(
+        debugger.run(None).unwrap(),
+        Stop::Breakpoint("multiple of 4".to_string())
+    );
+    assert_eq!(debugger.run(None).unwrap(), Stop::Done);
+    assert_eq!(debugger.run(None).unwrap(), Stop::Done);
+
+    assert_eq!(debugger.steps(), 10);
+    assert_eq!(
+        debugger.history().copied().collect::<Vec<_>>(),
+        [(8, 8), (9, 9), (10, 10)]
+    );
+    assert_eq!(seen.borrow().len(), 10);
+    assert_eq!(seen.borrow()[3], (4, 4));
+}
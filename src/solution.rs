@@ -0,0 +1,30 @@
+use anyhow::*;
+use std::fmt::Display;
+
+/// A day's puzzle: the input is parsed once and both parts work on the parsed value.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Parse the input exactly as read, instead of [`normalise`]d.
+    ///
+    /// [`normalise`]: crate::input::normalise
+    const RAW_INPUT: bool = false;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// Other ways of solving the parts, which `aoc compare` times and checks
+    /// against [`Solution::part1`] and [`Solution::part2`].
+    fn alternatives() -> Vec<Alternative<Self>> {
+        Vec::new()
+    }
+}
+
+/// A named second approach to one part of a [`Solution`].
+pub enum Alternative<S: Solution + ?Sized> {
+    Part1(&'static str, fn(&S::Input) -> Result<S::Answer1>),
+    Part2(&'static str, fn(&S::Input) -> Result<S::Answer2>),
+}
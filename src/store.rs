@@ -0,0 +1,197 @@
+use crate::answers::input_hash;
+use crate::input;
+use anyhow::*;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Name of the checksum file kept next to the inputs it covers.
+pub const FILE_NAME: &str = "checksums.json";
+
+/// Serialises load-modify-save of checksum files between threads, e.g. in `aoc run all`.
+static LOCK: Mutex<()> = Mutex::new(());
+
+/// SHA-256 checksums of the inputs in one directory, keyed by day. Each year's
+/// inputs have a directory of their own, see [`input::relative_path`].
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Checksums(BTreeMap<u8, String>);
+
+impl Checksums {
+    pub fn load(path: &Path) -> Result<Checksums> {
+        if !path.exists() {
+            return Ok(Checksums::default());
+        }
+        let json = read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        write(path, json + "\n").with_context(|| format!("writing {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8) -> Option<&str> {
+        self.0.get(&day).map(String::as_str)
+    }
+
+    pub fn record(&mut self, day: u8, checksum: String) {
+        self.0.insert(day, checksum);
+    }
+}
+
+/// The checksum file covering the input at `input_path`.
+pub fn checksums_path(input_path: &Path) -> PathBuf {
+    input_path.with_file_name(FILE_NAME)
+}
+
+/// How an input compares to its recorded checksum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Integrity {
+    New,
+    Unchanged,
+    Changed { recorded: String },
+}
+
+impl Integrity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Integrity::New => "new",
+            Integrity::Unchanged => "ok",
+            Integrity::Changed { .. } => "CHANGED",
+        }
+    }
+}
+
+/// Compares `input` against the checksum recorded for `day`, recording it on
+/// first use if `record` is set. A changed checksum is never overwritten.
+pub fn check(day: u8, input_path: &Path, input: &str, record: bool) -> Result<Integrity> {
+    check_hash(day, input_path, &input_hash(input), record)
+}
+
+/// [`check`] for an input whose checksum is already known, e.g. from streaming it.
+pub fn check_hash(day: u8, input_path: &Path, checksum: &str, record: bool) -> Result<Integrity> {
+    let _guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let path = checksums_path(input_path);
+    let mut checksums = Checksums::load(&path)?;
+
+    Ok(match checksums.get(day) {
+        Some(recorded) if recorded == checksum => Integrity::Unchanged,
+        Some(recorded) => Integrity::Changed {
+            recorded: recorded.to_string(),
+        },
+        None => {
+            if record {
+                checksums.record(day, checksum.to_string());
+                checksums.save(&path)?;
+            }
+            Integrity::New
+        }
+    })
+}
+
+/// Records a stored input's checksum on first use, logging a warning if it no longer matches.
+pub fn verify(day: u8, input_path: &Path, checksum: &str) -> Result<()> {
+    if let Integrity::Changed { recorded } = check_hash(day, input_path, checksum, true)? {
+        warn!(
+            "{} changed since it was first used (recorded sha256 {recorded}); \
+             remove day {day} from {} to accept the new input",
+            input_path.display(),
+            checksums_path(input_path).display()
+        );
+    }
+    Ok(())
+}
+
+/// Reads a stored input, logging a warning if it no longer matches its checksum.
+pub fn read_verified(day: u8, input_path: &Path) -> Result<String> {
+    let input =
+        read_to_string(input_path).with_context(|| format!("reading {}", input_path.display()))?;
+    verify(day, input_path, &input_hash(&input))?;
+
+    Ok(input)
+}
+
+/// A stored input as listed by `aoc inputs status`.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub path: PathBuf,
+    pub size: u64,
+    pub checksum: String,
+    pub integrity: Integrity,
+}
+
+/// Looks up `day`'s stored input without recording anything, or `None` if it has none.
+pub fn entry(year: u16, day: u8) -> Result<Option<Entry>> {
+    let Some(path) = input::find(year, day) else {
+        return Ok(None);
+    };
+    let input = read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+
+    Ok(Some(Entry {
+        year,
+        day,
+        size: input.len() as u64,
+        checksum: input_hash(&input),
+        integrity: check(day, &path, &input, false)?,
+        path,
+    }))
+}
+
+pub fn print_status(entries: &[Entry]) {
+    println!(
+        "{:>4} {:>3}  {:>8}  {:<64}  {:<7}  Path",
+        "Year", "Day", "Size", "SHA-256", "Status"
+    );
+    entries.iter().for_each(|entry| {
+        println!(
+            "{:>4} {:>3}  {:>8}  {:<64}  {:<7}  {}",
+            entry.year,
+            entry.day,
+            entry.size,
+            entry.checksum,
+            entry.integrity.as_str(),
+            entry.path.display()
+        )
+    });
+}
+
+#[test]
+fn records_then_detects_changes() {
+    let dir = std::env::temp_dir().join(format!("aoc-store-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let input_path = dir.join(input::file_name(6));
+
+    assert_eq!(
+        check(6, &input_path, "abc\n", false).unwrap(),
+        Integrity::New
+    );
+    assert!(!checksums_path(&input_path).exists());
+
+    assert_eq!(
+        check(6, &input_path, "abc\n", true).unwrap(),
+        Integrity::New
+    );
+    assert_eq!(
+        check(6, &input_path, "abc\n", true).unwrap(),
+        Integrity::Unchanged
+    );
+    assert_eq!(
+        check(6, &input_path, "abc", true).unwrap(),
+        Integrity::Changed {
+            recorded: input_hash("abc\n")
+        }
+    );
+    assert_eq!(
+        Checksums::load(&checksums_path(&input_path))
+            .unwrap()
+            .get(6),
+        Some(input_hash("abc\n").as_str())
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
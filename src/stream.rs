@@ -0,0 +1,146 @@
+//! Streaming access to line-oriented inputs too large to read whole, e.g.
+//! generated ones in the hundreds of megabytes.
+
+use crate::cancel;
+use crate::Solution;
+use anyhow::*;
+use sha2::{Digest, Sha256};
+use std::io::BufRead;
+use std::ops::AddAssign;
+
+/// How a streamed input splits into records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Records {
+    /// One record per non-blank line.
+    Lines,
+    /// Records of several lines, separated by blank lines.
+    Blocks,
+}
+
+/// Reads records one at a time, normalised like [`normalise`] does for whole
+/// inputs. Only the current record is held in memory.
+///
+/// [`normalise`]: crate::input::normalise
+pub struct RecordReader<R> {
+    reader: R,
+    records: Records,
+    line: String,
+    hasher: Sha256,
+    first: bool,
+}
+
+impl<R: BufRead> RecordReader<R> {
+    pub fn new(reader: R, records: Records) -> RecordReader<R> {
+        RecordReader {
+            reader,
+            records,
+            line: String::new(),
+            hasher: Sha256::new(),
+            first: true,
+        }
+    }
+
+    /// Hex SHA-256 of everything read so far; that of the whole input once
+    /// the records run out, matching [`input_hash`].
+    ///
+    /// [`input_hash`]: crate::answers::input_hash
+    pub fn input_hash(&self) -> String {
+        self.hasher
+            .clone()
+            .finalize()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+
+    /// The next line without its line ending and trailing whitespace, or `None` at the end.
+    fn next_line(&mut self) -> Result<Option<&str>> {
+        self.line.clear();
+        if self.reader.read_line(&mut self.line)? == 0 {
+            return Ok(None);
+        }
+        self.hasher.update(self.line.as_bytes());
+        let mut line = self.line.trim_end();
+        if self.first {
+            self.first = false;
+            line = line.strip_prefix('\u{feff}').unwrap_or(line);
+        }
+        Ok(Some(line))
+    }
+}
+
+impl<R: BufRead> Iterator for RecordReader<R> {
+    type Item = Result<String>;
+
+    fn next(&mut self) -> Option<Result<String>> {
+        let records = self.records;
+        let mut record = String::new();
+        loop {
+            match self.next_line() {
+                Err(e) => return Some(Err(e)),
+                Result::Ok(None) => return (!record.is_empty()).then_some(Ok(record)),
+                Result::Ok(Some("")) if record.is_empty() => {}
+                Result::Ok(Some("")) => return Some(Ok(record)),
+                Result::Ok(Some(line)) => {
+                    if !record.is_empty() {
+                        record.push('\n');
+                    }
+                    record.push_str(line);
+                    if records == Records::Lines {
+                        return Some(Ok(record));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A [`Solution`] that can also fold its input in one record at a time.
+pub trait Streaming: Solution {
+    const RECORDS: Records;
+    /// What the records fold into; ideally bounded, at worst smaller than the text.
+    type Totals: Default;
+
+    fn add(totals: &mut Self::Totals, record: &str) -> Result<()>;
+    fn answers(totals: Self::Totals) -> Result<(Self::Answer1, Self::Answer2)>;
+}
+
+/// [`Streaming::add`] for days whose answers sum over records: each record is
+/// parsed and solved as an input of its own.
+pub fn add_record<S: Solution>(totals: &mut (S::Answer1, S::Answer2), record: &str) -> Result<()>
+where
+    S::Answer1: AddAssign,
+    S::Answer2: AddAssign,
+{
+    let input = S::parse(record)?;
+    totals.0 += S::part1(&input)?;
+    totals.1 += S::part2(&input)?;
+    Ok(())
+}
+
+/// Folds every record into `S`'s answers, checking for cancellation between records.
+pub fn solve<S: Streaming>(
+    records: impl Iterator<Item = Result<String>>,
+) -> Result<(S::Answer1, S::Answer2)> {
+    let mut totals = S::Totals::default();
+    for record in records {
+        cancel::check()?;
+        S::add(&mut totals, &record?)?;
+    }
+    S::answers(totals)
+}
+
+#[test]
+fn records_are_normalised_lines_or_blocks() {
+    let input = "\u{feff}a 1\r\nb 2  \r\n\r\n\r\nc 3\n\n";
+    let lines = RecordReader::new(input.as_bytes(), Records::Lines)
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(lines, ["a 1", "b 2", "c 3"]);
+
+    let mut blocks = RecordReader::new(input.as_bytes(), Records::Blocks);
+    assert_eq!(blocks.next().unwrap().unwrap(), "a 1\nb 2");
+    assert_eq!(blocks.next().unwrap().unwrap(), "c 3");
+    assert!(blocks.next().is_none());
+    assert_eq!(blocks.input_hash(), crate::answers::input_hash(input));
+}
@@ -0,0 +1,140 @@
+use crate::answers::{input_hash, Answers};
+use crate::input;
+use crate::registry::{self, Day};
+use crate::runner::{quiet_panics, solve_with, SolveOptions};
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// Solved, but no answer is recorded for this input yet.
+    Missing,
+    NoInput,
+    Error,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+            Status::NoInput => "no input",
+            Status::Error => "ERROR",
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub error: Option<String>,
+}
+
+impl Check {
+    fn failed(day: &Day, part: u8, status: Status, error: String) -> Check {
+        Check {
+            year: day.year,
+            day: day.day,
+            part,
+            status,
+            answer: None,
+            expected: None,
+            error: Some(error),
+        }
+    }
+}
+
+fn verify_day(day: &Day, answers: &mut Answers, record: bool, timeout: Duration) -> Vec<Check> {
+    let input = match input::resolve(day.year, day.day, None).and_then(|source| source.read()) {
+        Ok(input) => input,
+        Err(e) => {
+            return [1, 2]
+                .map(|part| Check::failed(day, part, Status::NoInput, format!("{e:#}")))
+                .into()
+        }
+    };
+    let hash = input_hash(&input);
+    let options = SolveOptions {
+        timeout: Some(timeout),
+        show_progress: false,
+    };
+
+    solve_with(day, &input, options)
+        .into_iter()
+        .map(|solved| match solved.answer {
+            None => Check::failed(
+                day,
+                solved.part,
+                Status::Error,
+                solved.error.unwrap_or_default(),
+            ),
+            Some(answer) => {
+                let part = solved.part;
+                let expected = answers
+                    .get(day.year, day.day, &hash, part)
+                    .map(str::to_string);
+                let status = match &expected {
+                    None => Status::Missing,
+                    Some(expected) if *expected == answer => Status::Pass,
+                    Some(_) => Status::Fail,
+                };
+                if record {
+                    answers.record(day.year, day.day, &hash, part, answer.clone());
+                }
+                Check {
+                    year: day.year,
+                    day: day.day,
+                    part,
+                    status,
+                    answer: Some(answer),
+                    expected,
+                    error: None,
+                }
+            }
+        })
+        .collect()
+}
+
+/// Solves every registered day and compares against `answers`. With `record`,
+/// the computed answers are written into `answers` as well. A day that panics
+/// or runs past `timeout` is reported as an error rather than stopping the rest.
+pub fn verify(answers: &mut Answers, record: bool, timeout: Duration) -> Vec<Check> {
+    quiet_panics(|| {
+        registry::days()
+            .flat_map(|day| verify_day(day, answers, record, timeout))
+            .collect()
+    })
+}
+
+pub fn print_table(checks: &[Check]) {
+    println!(
+        "{:>4} {:>3} {:>4}  {:<8}  {:<20}  Expected",
+        "Year", "Day", "Part", "Status", "Answer"
+    );
+    checks.iter().for_each(|check| {
+        println!(
+            "{:>4} {:>3} {:>4}  {:<8}  {:<20}  {}",
+            check.year,
+            check.day,
+            check.part,
+            check.status,
+            check.answer.as_deref().unwrap_or("-"),
+            check
+                .expected
+                .as_deref()
+                .or(check
+                    .error
+                    .as_deref()
+                    .map(|e| e.lines().next().unwrap_or(e)))
+                .unwrap_or("-"),
+        )
+    });
+}
@@ -0,0 +1,450 @@
+//! `aoc view`: steps through a [`Recording`] in the terminal, either one saved
+//! earlier or one still being made by a solution running alongside.
+
+use crate::recording::{self, Grid, Recording, Sink};
+use crate::registry::Day;
+use crate::runner::{catch, quiet_panics};
+use anyhow::*;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{cursor, ExecutableCommand, QueueableCommand};
+use std::collections::HashSet;
+use std::io::{stdout, Write};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Frames a running solution may get ahead of the viewer before it waits.
+const FRAME_BUFFER: usize = 1024;
+/// Longest the viewer spends taking in new frames between redraws.
+const INTAKE_BUDGET: Duration = Duration::from_millis(20);
+/// Lines below the map for the status and key help.
+const STATUS_LINES: usize = 2;
+const HELP: &str =
+    "space play  ,/. step  [/] speed  g jump  home/end  arrows scroll  +/- zoom  0 fit  q quit";
+
+/// A solution running on another thread, sending its frames as it goes.
+pub struct Live {
+    frames: Receiver<(String, String)>,
+    outcome: Receiver<String>,
+}
+
+/// Solves one part of `day` on a new thread, recording its frames for the viewer.
+pub fn live(day: &'static Day, part: u8, input: String) -> Live {
+    let (frames_tx, frames) = mpsc::sync_channel(FRAME_BUFFER);
+    let (outcome_tx, outcome) = mpsc::channel();
+    thread::spawn(move || {
+        let (result, _) = recording::record(Sink::Live(frames_tx), || {
+            catch(|| day.part(part, &day.parse(&input)?))
+        });
+        let _ = outcome_tx.send(match result {
+            Result::Ok(answer) => format!("answer {answer}"),
+            Err(e) => format!("failed: {e:#}"),
+        });
+    });
+
+    Live { frames, outcome }
+}
+
+/// One character on screen, and whether it covers a cell the current frame changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub c: char,
+    pub changed: bool,
+}
+
+/// What the viewer shows and how; everything but the terminal itself.
+pub struct Viewer {
+    recording: Recording,
+    live: Option<Live>,
+    /// How the live solution ended, once it has.
+    outcome: Option<String>,
+    index: usize,
+    grid: Option<Grid>,
+    playing: bool,
+    /// Frames per second while playing.
+    speed: f64,
+    /// Grid cells per character along each axis; 1 shows every cell.
+    zoom: usize,
+    /// Grid cell shown at the top left.
+    left: usize,
+    top: usize,
+    /// The frame number being typed after `g`.
+    jump: Option<String>,
+}
+
+impl Viewer {
+    pub fn new(recording: Recording, live: Option<Live>) -> Viewer {
+        Viewer {
+            recording,
+            live,
+            outcome: None,
+            index: 0,
+            grid: None,
+            playing: false,
+            speed: 10.0,
+            zoom: 1,
+            left: 0,
+            top: 0,
+            jump: None,
+        }
+    }
+
+    pub fn into_recording(self) -> Recording {
+        self.recording
+    }
+
+    /// Takes in the frames a live solution sent since the last call, for at
+    /// most `budget`. Returns whether anything changed.
+    pub fn intake(&mut self, budget: Duration) -> bool {
+        let Some(live) = &self.live else {
+            return false;
+        };
+        let start = Instant::now();
+        let mut changed = false;
+        while start.elapsed() < budget {
+            match live.frames.try_recv() {
+                Result::Ok((caption, text)) => {
+                    self.recording.push(caption, &text);
+                    changed = true;
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.outcome = live.outcome.try_recv().ok();
+                    self.live = None;
+                    return true;
+                }
+            }
+        }
+        if self.grid.is_none() && !self.recording.is_empty() {
+            self.grid = Some(self.recording.frame(0));
+        }
+        changed
+    }
+
+    fn last(&self) -> usize {
+        self.recording.len().saturating_sub(1)
+    }
+
+    /// Shows frame `index`, clamped to the frames recorded so far.
+    pub fn seek(&mut self, index: usize) {
+        if self.recording.is_empty() {
+            return;
+        }
+        let index = index.min(self.last());
+        match &mut self.grid {
+            Some(grid) if index == self.index + 1 => self.recording.advance(grid, self.index),
+            _ => self.grid = Some(self.recording.frame(index)),
+        }
+        self.index = index;
+    }
+
+    /// Moves `frames` frames on while playing, stopping at the last one unless
+    /// more may still arrive.
+    pub fn play(&mut self, frames: usize) {
+        if self.index == self.last() && self.live.is_none() {
+            self.playing = false;
+        }
+        for _ in 0..frames.min(self.last() - self.index) {
+            self.seek(self.index + 1);
+        }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    pub fn speed(&self) -> f64 {
+        self.speed
+    }
+
+    /// Zooms out until the whole map fits in `width` by `height` characters.
+    fn fit(&mut self, width: usize, height: usize) {
+        if let Some(grid) = &self.grid {
+            self.zoom = grid
+                .width
+                .div_ceil(width.max(1))
+                .max(grid.height.div_ceil(height.max(1)))
+                .max(1);
+            self.left = 0;
+            self.top = 0;
+        }
+    }
+
+    /// Handles a key press on a `width` by `height` terminal. Returns `false` to quit.
+    pub fn key(&mut self, key: KeyEvent, width: usize, height: usize) -> bool {
+        let map_height = height.saturating_sub(STATUS_LINES);
+        if let Some(jump) = &mut self.jump {
+            match key.code {
+                KeyCode::Char(digit) if digit.is_ascii_digit() => jump.push(digit),
+                KeyCode::Backspace => {
+                    jump.pop();
+                }
+                KeyCode::Enter => {
+                    if let Result::Ok(frame) = jump.parse::<usize>() {
+                        self.playing = false;
+                        self.seek(frame);
+                    }
+                    self.jump = None;
+                }
+                _ => self.jump = None,
+            }
+            return true;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char(' ') => self.playing = !self.playing,
+            KeyCode::Char('.') => {
+                self.playing = false;
+                self.seek(self.index + 1);
+            }
+            KeyCode::Char(',') => {
+                self.playing = false;
+                self.seek(self.index.saturating_sub(1));
+            }
+            KeyCode::Home => self.seek(0),
+            KeyCode::End => self.seek(self.last()),
+            KeyCode::Char('g') => self.jump = Some(String::new()),
+            KeyCode::Char('[') => self.speed = (self.speed / 2.0).max(0.25),
+            KeyCode::Char(']') => self.speed = (self.speed * 2.0).min(10_000.0),
+            KeyCode::Char('+') | KeyCode::Char('=') => self.zoom = (self.zoom / 2).max(1),
+            KeyCode::Char('-') => self.zoom = (self.zoom * 2).min(1024),
+            KeyCode::Char('0') => self.fit(width, map_height),
+            KeyCode::Left | KeyCode::Char('h') => self.left = self.left.saturating_sub(self.zoom),
+            KeyCode::Right | KeyCode::Char('l') => self.left += self.zoom,
+            KeyCode::Up | KeyCode::Char('k') => self.top = self.top.saturating_sub(self.zoom),
+            KeyCode::Down | KeyCode::Char('j') => self.top += self.zoom,
+            KeyCode::PageUp => self.top = self.top.saturating_sub(self.zoom * map_height / 2),
+            KeyCode::PageDown => self.top += self.zoom * map_height / 2,
+            _ => {}
+        }
+        true
+    }
+
+    /// The visible part of the map as `height` rows of up to `width` characters.
+    /// Zoomed out, each character shows its block's rarest character, so small
+    /// things like a robot stay visible on a big map.
+    pub fn screen(&mut self, width: usize, height: usize) -> Vec<Vec<Cell>> {
+        let Some(grid) = &self.grid else {
+            return Vec::new();
+        };
+        // Don't scroll past the map's far edges.
+        let zoom = self.zoom;
+        self.left = self
+            .left
+            .min(grid.width.saturating_sub(width * zoom))
+            .min(grid.width.saturating_sub(1));
+        self.top = self
+            .top
+            .min(grid.height.saturating_sub(height * zoom))
+            .min(grid.height.saturating_sub(1));
+
+        let changed = self
+            .recording
+            .changed(self.index)
+            .map(|changed| changed.collect::<HashSet<_>>())
+            .unwrap_or_default();
+
+        (self.top..grid.height)
+            .step_by(zoom)
+            .take(height)
+            .map(|top| {
+                (self.left..grid.width)
+                    .step_by(zoom)
+                    .take(width)
+                    .map(|left| {
+                        let block = (top..(top + zoom).min(grid.height)).flat_map(|y| {
+                            (left..(left + zoom).min(grid.width)).map(move |x| (x, y))
+                        });
+                        let mut c = grid.get(left, top);
+                        let mut cell_changed = false;
+                        for (x, y) in block {
+                            let candidate = grid.get(x, y);
+                            if grid.count(candidate) < grid.count(c) {
+                                c = candidate;
+                            }
+                            cell_changed |= changed.contains(&(y * grid.width + x));
+                        }
+                        Cell {
+                            c,
+                            changed: cell_changed,
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The line under the map: the frame, its caption and how playback stands.
+    pub fn status(&self) -> String {
+        if let Some(jump) = &self.jump {
+            return format!("jump to frame: {jump}_");
+        }
+        let state = match (&self.live, &self.outcome) {
+            (Some(_), _) => "running".to_string(),
+            (None, Some(outcome)) => outcome.clone(),
+            (None, None) => "recorded".to_string(),
+        };
+        if self.recording.is_empty() {
+            return format!(
+                "{}  waiting for the first frame  [{state}]",
+                self.recording.title
+            );
+        }
+        let grid = self.grid.as_ref().map_or((0, 0), |g| (g.width, g.height));
+        format!(
+            "{}  frame {}/{}  {}  [{}, {}x, {}/s, {}x{} at {},{}, {state}]",
+            self.recording.title,
+            self.index,
+            self.last(),
+            self.recording.caption(self.index),
+            if self.playing { "playing" } else { "paused" },
+            self.zoom,
+            self.speed,
+            grid.0,
+            grid.1,
+            self.left,
+            self.top,
+        )
+    }
+}
+
+/// Raw mode on the alternate screen, restored when dropped, even on a panic.
+struct Screen;
+
+impl Screen {
+    fn enter() -> Result<Screen> {
+        terminal::enable_raw_mode()?;
+        stdout()
+            .execute(EnterAlternateScreen)?
+            .execute(cursor::Hide)?;
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = stdout()
+            .execute(cursor::Show)
+            .and_then(|out| out.execute(LeaveAlternateScreen));
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn draw(viewer: &mut Viewer, width: usize, height: usize) -> Result<()> {
+    let mut out = stdout().lock();
+    let map_height = height.saturating_sub(STATUS_LINES);
+    let rows = viewer.screen(width, map_height);
+    for y in 0..map_height {
+        out.queue(cursor::MoveTo(0, y as u16))?;
+        for cell in rows.get(y).into_iter().flatten() {
+            if cell.changed {
+                out.queue(SetAttribute(Attribute::Reverse))?
+                    .queue(Print(cell.c))?
+                    .queue(SetAttribute(Attribute::NoReverse))?;
+            } else {
+                out.queue(Print(cell.c))?;
+            }
+        }
+        out.queue(Clear(ClearType::UntilNewLine))?;
+    }
+    for (y, line) in [viewer.status(), HELP.to_string()].iter().enumerate() {
+        let line = line.chars().take(width).collect::<String>();
+        out.queue(cursor::MoveTo(0, (map_height + y) as u16))?
+            .queue(Print(line))?
+            .queue(Clear(ClearType::UntilNewLine))?;
+    }
+    out.flush()?;
+
+    Ok(())
+}
+
+/// Runs the viewer until it's quit, returning the recording, e.g. to save it.
+pub fn view(recording: Recording, live: Option<Live>) -> Result<Recording> {
+    let mut viewer = Viewer::new(recording, live);
+    viewer.intake(INTAKE_BUDGET);
+    viewer.seek(0);
+
+    // The live solution's panics are shown in the status line, not over the
+    // screen. The viewer's own become errors, reported once `_screen` has
+    // restored the terminal.
+    quiet_panics(|| {
+        let _screen = Screen::enter()?;
+        catch(|| run(&mut viewer))
+    })?;
+
+    Ok(viewer.into_recording())
+}
+
+/// Draws and handles keys until the viewer is quit.
+fn run(viewer: &mut Viewer) -> Result<()> {
+    let mut last_frame = Instant::now();
+    loop {
+        let (width, height) = terminal::size()?;
+        let (width, height) = (width as usize, height as usize);
+        draw(viewer, width, height)?;
+
+        if event::poll(Duration::from_millis(15))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Release && !viewer.key(key, width, height) {
+                    break;
+                }
+            }
+        }
+        viewer.intake(INTAKE_BUDGET);
+        if viewer.is_playing() {
+            let due = (last_frame.elapsed().as_secs_f64() * viewer.speed()) as usize;
+            if due > 0 {
+                viewer.play(due);
+                last_frame = Instant::now();
+            }
+        } else {
+            last_frame = Instant::now();
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn zoomed_out_cells_show_rare_characters_and_changes() {
+    let mut recording = Recording::new("test");
+    recording.push("start".to_string(), "....\n....\n...@\n....\n");
+    recording.push("move".to_string(), "....\n....\n....\n..@.\n");
+    let mut viewer = Viewer::new(recording, None);
+    viewer.seek(1);
+    assert_eq!(
+        viewer.status(),
+        "test  frame 1/1  move  [paused, 1x, 10/s, 4x4 at 0,0, recorded]"
+    );
+
+    viewer.zoom = 2;
+    let text = |screen: Vec<Vec<Cell>>| {
+        screen
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.c).collect::<String>())
+            .collect::<Vec<_>>()
+    };
+    let screen = viewer.screen(10, 10);
+    assert_eq!(text(screen.clone()), ["..", ".@"]);
+    assert_eq!(
+        screen[1]
+            .iter()
+            .map(|cell| cell.changed)
+            .collect::<Vec<_>>(),
+        [false, true]
+    );
+
+    viewer.zoom = 1;
+    viewer.left = 3;
+    viewer.top = 3;
+    assert_eq!(text(viewer.screen(2, 2)), ["..", "@."]);
+    assert_eq!((viewer.left, viewer.top), (2, 2));
+    viewer.seek(0);
+    assert!(viewer
+        .screen(2, 2)
+        .iter()
+        .flatten()
+        .all(|cell| !cell.changed));
+}
@@ -0,0 +1,248 @@
+//! `aoc watch`: re-solves a day's examples and input whenever they change, and
+//! rebuilds and re-executes itself when the source changes.
+
+use crate::examples::{self, Example, ANY_ANSWER};
+use crate::input;
+use crate::registry::Day;
+use crate::report::{Record, Status};
+use crate::runner::{quiet_panics, solve_with, SolveOptions};
+use anyhow::*;
+use notify::{Event, RecursiveMode, Watcher};
+use std::collections::BTreeMap;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+/// Carries the last answers across a re-exec, so the first round after a
+/// rebuild still shows what changed.
+const PREVIOUS_VAR: &str = "AOC_WATCH_PREVIOUS";
+
+/// Answers from one round, by example name (or `input`) and part.
+pub type Answers = BTreeMap<String, BTreeMap<u8, String>>;
+
+/// Describes one part's result, comparing it to the expected answer for an
+/// example and to the previous round's answer.
+pub fn describe(record: &Record, expected: Option<&str>, previous: Option<&str>) -> String {
+    let Some(answer) = &record.answer else {
+        return format!(
+            "{}: {}",
+            record.status.as_str(),
+            record.error.as_deref().unwrap_or_default()
+        );
+    };
+
+    let check = match expected {
+        Some(ANY_ANSWER) | None => String::new(),
+        Some(expected) if expected == answer => " ok".to_string(),
+        Some(expected) => format!(" FAIL, expected {expected}"),
+    };
+    let change = match previous {
+        Some(previous) if previous != answer => format!(" (was {previous})"),
+        _ => String::new(),
+    };
+    format!("{answer}{check}{change}")
+}
+
+/// Solves the examples and then the input, printing each part against `previous`.
+fn solve_round(
+    day: &Day,
+    input: Option<&str>,
+    timeout: Duration,
+    previous: &Answers,
+) -> Result<Answers> {
+    let options = SolveOptions {
+        timeout: Some(timeout),
+        show_progress: true,
+    };
+    let mut sources = Example::load_day(day.year, day.day)?
+        .into_iter()
+        .map(|example| (example.name, example.input, example.answers))
+        .collect::<Vec<_>>();
+    let missing = match input::resolve(day.year, day.day, input).and_then(|source| source.read()) {
+        Result::Ok(input) => {
+            sources.push(("input".to_string(), input, BTreeMap::new()));
+            None
+        }
+        Err(e) => Some(e),
+    };
+
+    let mut answers = Answers::new();
+    for (name, input, expected) in sources {
+        let records = quiet_panics(|| solve_with(day, &input, options));
+        for record in records {
+            let previous = previous
+                .get(&name)
+                .and_then(|parts| parts.get(&record.part));
+            let description = describe(
+                &record,
+                expected.get(&record.part).map(String::as_str),
+                previous.map(String::as_str),
+            );
+            println!("  {name:<16} part {}: {description}", record.part);
+            if let (Status::Ok, Some(answer)) = (record.status, record.answer) {
+                answers
+                    .entry(name.clone())
+                    .or_default()
+                    .insert(record.part, answer);
+            }
+        }
+    }
+    if let Some(e) = missing {
+        println!("  input: {e:#}");
+    }
+
+    Ok(answers)
+}
+
+/// What a batch of file events asks for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Change {
+    None,
+    /// An example or the input changed; holds the first such path.
+    Data(PathBuf),
+    Source,
+}
+
+fn classify(paths: &[PathBuf], examples: &Path, input: Option<&Path>) -> Change {
+    if paths
+        .iter()
+        .any(|path| path.extension().is_some_and(|ext| ext == "rs"))
+    {
+        return Change::Source;
+    }
+    paths
+        .iter()
+        .find(|path| path.starts_with(examples) || Some(path.as_path()) == input)
+        .map_or(Change::None, |path| Change::Data(path.clone()))
+}
+
+/// Waits for the next event, then collects the rest of its burst, since
+/// editors often write a file in several steps.
+fn next_batch(events: &Receiver<notify::Result<Event>>) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    let mut event = events.recv()?;
+    loop {
+        match event {
+            Result::Ok(event) if !event.kind.is_access() => paths.extend(event.paths),
+            Result::Ok(_) => {}
+            Err(e) => log::warn!("watching: {e}"),
+        }
+        match events.recv_timeout(Duration::from_millis(100)) {
+            Result::Ok(next) => event = next,
+            Err(_) => return Ok(paths),
+        }
+    }
+}
+
+/// Rebuilds `aoc` with the same profile and features as the running binary.
+fn rebuild() -> Result<bool> {
+    let mut cargo = Command::new(env::var_os("CARGO").unwrap_or("cargo".into()));
+    cargo
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["build", "--bin", "aoc"]);
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    if cfg!(feature = "alloc-stats") {
+        cargo.args(["--features", "alloc-stats"]);
+    }
+    Ok(cargo.status().context("running cargo build")?.success())
+}
+
+/// Replaces this process with the freshly built `exe`, passing `answers` on.
+fn reexec(exe: &Path, answers: &Answers) -> Result<()> {
+    let mut command = Command::new(exe);
+    command
+        .args(env::args_os().skip(1))
+        .env(PREVIOUS_VAR, serde_json::to_string(answers)?);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        Err(command.exec().into())
+    }
+    #[cfg(not(unix))]
+    {
+        let status = command.status()?;
+        std::process::exit(status.code().unwrap_or(1))
+    }
+}
+
+/// Solves `day` now and again after every change to its examples, its input
+/// or the crate's source, until interrupted.
+pub fn watch(day: &Day, input: Option<&str>, timeout: Duration) -> Result<()> {
+    // Looked up before rebuilding, as Linux reports a replaced binary as deleted.
+    let exe = env::current_exe()?;
+    let examples = examples::dir(day.year, day.day);
+    let input_path = match input {
+        Some("-") => bail!("can't watch stdin"),
+        Some(path) => Some(Path::new(path).canonicalize()?),
+        None => input::find(day.year, day.day)
+            .map(|path| path.canonicalize())
+            .transpose()?,
+    };
+    let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+
+    let (tx, events) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(&source, RecursiveMode::Recursive)?;
+    if examples.is_dir() {
+        watcher.watch(&examples, RecursiveMode::NonRecursive)?;
+    }
+    if let Some(dir) = input_path.as_deref().and_then(Path::parent) {
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+    }
+    let examples = examples.canonicalize().unwrap_or(examples);
+
+    let mut answers = match env::var(PREVIOUS_VAR) {
+        Result::Ok(previous) => serde_json::from_str(&previous).unwrap_or_default(),
+        Err(_) => Answers::new(),
+    };
+    println!("{day}");
+    answers = solve_round(day, input, timeout, &answers)?;
+
+    loop {
+        let paths = next_batch(&events)?;
+        match classify(&paths, &examples, input_path.as_deref()) {
+            Change::None => continue,
+            Change::Data(path) => {
+                println!("\n{} changed", path.display());
+                answers = solve_round(day, input, timeout, &answers)?;
+            }
+            Change::Source => {
+                println!("\nsource changed, rebuilding");
+                if rebuild()? {
+                    return reexec(&exe, &answers);
+                }
+                println!("build failed; waiting for the next change");
+            }
+        }
+    }
+}
+
+#[test]
+fn describe_compares_with_expected_and_previous() {
+    let record = Record {
+        year: 2024,
+        day: 1,
+        part: 1,
+        answer: Some("11".to_string()),
+        parse_ns: None,
+        solve_ns: None,
+        input_hash: String::new(),
+        status: Status::Ok,
+        error: None,
+        parse_alloc: None,
+        solve_alloc: None,
+    };
+
+    assert_eq!(describe(&record, Some("11"), Some("11")), "11 ok");
+    assert_eq!(describe(&record, Some("12"), None), "11 FAIL, expected 12");
+    assert_eq!(
+        describe(&record, Some(ANY_ANSWER), Some("10")),
+        "11 (was 10)"
+    );
+    assert_eq!(describe(&record, None, None), "11");
+}
@@ -0,0 +1,52 @@
+//! Advent of Code 2024.
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+use crate::registry::Day;
+use day01::Day01;
+use day02::Day02;
+use day03::Day03;
+use day04::Day04;
+use day05::Day05;
+use day06::Day06;
+use day07::Day07;
+use day08::Day08;
+use day09::Day09;
+use day10::Day10;
+use day11::Day11;
+use day12::Day12;
+use day13::Day13;
+use day14::Day14;
+use day15::Day15;
+
+pub static DAYS: &[Day] = &[
+    Day::new::<Day01>(2024, 1).streaming::<Day01>(),
+    Day::new::<Day02>(2024, 2).streaming::<Day02>(),
+    Day::new::<Day03>(2024, 3),
+    Day::new::<Day04>(2024, 4),
+    Day::new::<Day05>(2024, 5),
+    Day::new::<Day06>(2024, 6).debuggable::<Day06>(),
+    Day::new::<Day07>(2024, 7).streaming::<Day07>(),
+    Day::new::<Day08>(2024, 8),
+    Day::new::<Day09>(2024, 9),
+    Day::new::<Day10>(2024, 10),
+    Day::new::<Day11>(2024, 11),
+    Day::new::<Day12>(2024, 12),
+    Day::new::<Day13>(2024, 13).streaming::<Day13>(),
+    Day::new::<Day14>(2024, 14).streaming::<Day14>(),
+    Day::new::<Day15>(2024, 15).debuggable::<Day15>(),
+];
@@ -0,0 +1,87 @@
+use crate::stream::{Records, Streaming};
+use crate::{value, Solution};
+use anyhow::*;
+use std::collections::HashMap;
+use std::iter::zip;
+
+pub struct Day01;
+
+/// Parses a line of two whitespace-separated location ids.
+pub fn parse_pair(line: &str) -> Result<(u32, u32)> {
+    let (a, b) = line
+        .split_once(' ')
+        .with_context(|| format!("missing separator in {line:?}"))?;
+    Ok((a.trim().parse::<u32>()?, b.trim().parse::<u32>()?))
+}
+
+/// Part 2's similarity score, counting the right list once instead of once per left id.
+pub fn similarity(distance_a: &[u32], distance_b: &[u32]) -> u64 {
+    let mut counts: HashMap<u32, u64> = HashMap::new();
+    distance_b
+        .iter()
+        .for_each(|&b| *counts.entry(b).or_default() += 1);
+
+    distance_a
+        .iter()
+        .map(|&a| u64::from(a) * counts.get(&a).copied().unwrap_or_default())
+        .sum()
+}
+
+impl Solution for Day01 {
+    type Input = (Vec<u32>, Vec<u32>);
+    // Ids reach 4 billion, so even two lines can overflow a `u32` sum.
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (mut distance_a, mut distance_b): (Vec<u32>, Vec<u32>) = input
+            .lines()
+            .map(parse_pair)
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        distance_a.sort();
+        distance_b.sort();
+        value!(pairs = distance_a.len());
+
+        Ok((distance_a, distance_b))
+    }
+
+    fn part1((distance_a, distance_b): &Self::Input) -> Result<u64> {
+        let mut distance = 0u64;
+        zip(distance_a, distance_b).for_each(|(a, b)| distance += u64::from(a.abs_diff(*b)));
+
+        Ok(distance)
+    }
+
+    fn part2((distance_a, distance_b): &Self::Input) -> Result<u64> {
+        let mut similarity = 0u64;
+
+        distance_a.iter().for_each(|a| {
+            similarity += u64::from(*a) * distance_b.iter().filter(|&b| a == b).count() as u64
+        });
+
+        Ok(similarity)
+    }
+}
+
+/// Keeps only the ids, which still have to be sorted once they're all in.
+impl Streaming for Day01 {
+    const RECORDS: Records = Records::Lines;
+    type Totals = (Vec<u32>, Vec<u32>);
+
+    fn add((distance_a, distance_b): &mut Self::Totals, record: &str) -> Result<()> {
+        let (a, b) = parse_pair(record)?;
+        distance_a.push(a);
+        distance_b.push(b);
+        Ok(())
+    }
+
+    fn answers((mut distance_a, mut distance_b): Self::Totals) -> Result<(u64, u64)> {
+        distance_a.sort();
+        distance_b.sort();
+        let similarity = similarity(&distance_a, &distance_b);
+        Ok((Day01::part1(&(distance_a, distance_b))?, similarity))
+    }
+}
@@ -0,0 +1,82 @@
+use crate::stream::{add_record, Records, Streaming};
+use crate::{value, Solution};
+use anyhow::*;
+
+pub struct Day02;
+
+/// Parses one report's space-separated levels.
+pub fn parse_levels(line: &str) -> Result<Vec<i32>> {
+    Ok(line
+        .split_whitespace()
+        .map(|l| l.parse::<i32>())
+        .collect::<Result<Vec<_>, _>>()?)
+}
+
+/// Whether the levels all increase or all decrease, by 1 to 3 each step.
+/// Reports with fewer than two levels have no steps, so they're safe.
+pub fn check_is_safe(levels: &[i32]) -> bool {
+    let diffs = levels
+        .iter()
+        .zip(levels.iter().skip(1))
+        .map(|(l, s)| i64::from(*l) - i64::from(*s))
+        .collect::<Vec<_>>();
+
+    let Some(sign) = diffs.first().map(|d| d.signum()) else {
+        return true;
+    };
+    diffs.iter().all(|d| d.signum() == sign && d.abs().le(&3))
+}
+
+/// Whether the report is safe once at most one level is removed.
+pub fn check_is_safe_dampened(levels: &[i32]) -> bool {
+    let mut combinations = vec![levels.to_vec()];
+    (0..levels.len()).for_each(|i| {
+        let mut new_diffs = levels.to_vec();
+        new_diffs.remove(i);
+        combinations.push(new_diffs);
+    });
+
+    combinations.iter().any(|d| check_is_safe(d))
+}
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let reports = input
+            .lines()
+            .map(parse_levels)
+            .collect::<Result<Vec<_>>>()?;
+        value!(reports = reports.len());
+        Ok(reports)
+    }
+
+    fn part1(reports: &Self::Input) -> Result<usize> {
+        Ok(reports
+            .iter()
+            .filter(|levels| check_is_safe(levels))
+            .count())
+    }
+
+    fn part2(reports: &Self::Input) -> Result<usize> {
+        Ok(reports
+            .iter()
+            .filter(|levels| check_is_safe_dampened(levels))
+            .count())
+    }
+}
+
+impl Streaming for Day02 {
+    const RECORDS: Records = Records::Lines;
+    type Totals = (usize, usize);
+
+    fn add(totals: &mut (usize, usize), record: &str) -> Result<()> {
+        add_record::<Day02>(totals, record)
+    }
+
+    fn answers(totals: (usize, usize)) -> Result<(usize, usize)> {
+        Ok(totals)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{value, Solution};
+use anyhow::*;
+use regex::Regex;
+
+pub struct Day03;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+impl Solution for Day03 {
+    type Input = Vec<Instruction>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    /// Picks the instructions out of the corrupted memory; `mul`'s operands
+    /// are 1 to 3 digits, anything longer is corrupted.
+    fn parse(input: &str) -> Result<Self::Input> {
+        let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)")?;
+        let instructions = re
+            .captures_iter(input)
+            .map(|c| {
+                Ok(match (&c[0], c.get(1), c.get(2)) {
+                    ("do()", ..) => Instruction::Do,
+                    ("don't()", ..) => Instruction::Dont,
+                    (_, Some(left), Some(right)) => {
+                        Instruction::Mul(left.as_str().parse()?, right.as_str().parse()?)
+                    }
+                    (other, ..) => bail!("unexpected instruction {other:?}"),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        value!(instructions = instructions.len());
+        Ok(instructions)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<u32> {
+        value!(
+            muls = instructions
+                .iter()
+                .filter(|i| matches!(i, Instruction::Mul(..)))
+                .count()
+        );
+
+        Ok(instructions.iter().fold(0u32, |acc, i| match i {
+            Instruction::Mul(left, right) => acc + left * right,
+            _ => acc,
+        }))
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<u32> {
+        Ok(instructions
+            .iter()
+            .fold((0u32, true), |(acc, enabled), i| match i {
+                Instruction::Do => (acc, true),
+                Instruction::Dont => (acc, false),
+                Instruction::Mul(left, right) if enabled => (acc + left * right, true),
+                Instruction::Mul(..) => (acc, false),
+            })
+            .0)
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{value, Solution};
+use anyhow::*;
+use std::collections::HashMap;
+
+pub struct Day04;
+
+/// Counts `XMAS` in every direction, including backwards and diagonally.
+pub fn count_xmas(coord_map: &HashMap<(i32, i32), char>) -> u32 {
+    let neighbors = [
+        (-1, -1),
+        (0, -1),
+        (1, -1),
+        (-1, 0),
+        (1, 0),
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+    ];
+
+    coord_map.iter().fold(0, |acc, ((x, y), c)| match c {
+        &'X' => {
+            acc + neighbors.iter().fold(0, |n_acc, (x_dir, y_dir)| {
+                match coord_map.get(&(x + x_dir, y + y_dir)) {
+                    Some('M') => match coord_map.get(&(x + 2 * x_dir, y + 2 * y_dir)) {
+                        Some('A') => match coord_map.get(&(x + 3 * x_dir, y + 3 * y_dir)) {
+                            Some('S') => n_acc + 1,
+                            _ => n_acc,
+                        },
+                        _ => n_acc,
+                    },
+                    _ => n_acc,
+                }
+            })
+        }
+        _ => acc,
+    })
+}
+
+/// Counts two `MAS` crossing in an X.
+pub fn count_x_mas(coord_map: &HashMap<(i32, i32), char>) -> u32 {
+    coord_map.iter().fold(0, |acc, ((x, y), c)| match c {
+        &'A' => {
+            let x_vec = [
+                coord_map.get(&(x - 1, y - 1)),
+                coord_map.get(&(x + 1, y + 1)),
+                coord_map.get(&(x - 1, y + 1)),
+                coord_map.get(&(x + 1, y - 1)),
+            ];
+
+            match x_vec.into_iter().collect::<Option<Vec<_>>>() {
+                None => acc,
+                Some(x_vec) => match x_vec[..] {
+                    ['M', 'S', 'M', 'S'] => acc + 1,
+                    ['M', 'S', 'S', 'M'] => acc + 1,
+                    ['S', 'M', 'M', 'S'] => acc + 1,
+                    ['S', 'M', 'S', 'M'] => acc + 1,
+                    _ => acc,
+                },
+            }
+        }
+        _ => acc,
+    })
+}
+
+/// Maps each letter's `(x, y)` position to the letter.
+pub fn parse_with_coords(input: &str) -> HashMap<(i32, i32), char> {
+    let mut map = HashMap::new();
+    input.lines().enumerate().for_each(|(y, line)| {
+        line.chars().enumerate().for_each(|(x, ch)| {
+            map.insert((x as i32, y as i32), ch);
+        });
+    });
+
+    map
+}
+
+impl Solution for Day04 {
+    type Input = HashMap<(i32, i32), char>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_with_coords(input))
+    }
+
+    fn part1(coord_map: &Self::Input) -> Result<u32> {
+        value!(xs = coord_map.values().filter(|&&c| c == 'X').count());
+        Ok(count_xmas(coord_map))
+    }
+
+    fn part2(coord_map: &Self::Input) -> Result<u32> {
+        Ok(count_x_mas(coord_map))
+    }
+}
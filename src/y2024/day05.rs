@@ -0,0 +1,107 @@
+use crate::{value, Solution};
+use anyhow::*;
+use regex::Regex;
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+pub struct Day05;
+
+pub struct Manuals {
+    /// Pages that must come after each page.
+    pub rules: HashMap<u32, Vec<u32>>,
+    pub manuals: Vec<Vec<u32>>,
+}
+
+/// Whether any page in `manual` comes after a page the rules put after it.
+pub fn is_unordered(rules: &HashMap<u32, Vec<u32>>, manual: &[u32]) -> bool {
+    (0..manual.len()).any(|i| {
+        let page = manual[i];
+        match rules.get(&page) {
+            None => false,
+            Some(page_rules) => manual[0..i].iter().any(|j| page_rules.contains(j)),
+        }
+    })
+}
+
+impl Solution for Day05 {
+    type Input = Manuals;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rules, manuals) = input
+            .split_once("\n\n")
+            .context("missing blank line between rules and manuals")?;
+
+        let (rules, manuals) = (parse_rules(rules)?, parse_manuals(manuals)?);
+        value!(rules = rules.len(), manuals = manuals.len());
+
+        Ok(Manuals { rules, manuals })
+    }
+
+    fn part1(Manuals { rules, manuals }: &Manuals) -> Result<u32> {
+        Ok(manuals
+            .iter()
+            .fold(0, |acc, manual| match is_unordered(rules, manual) {
+                true => acc,
+                false => acc + manual.get(manual.len() / 2).unwrap(),
+            }))
+    }
+
+    fn part2(Manuals { rules, manuals }: &Manuals) -> Result<u32> {
+        Ok(manuals
+            .iter()
+            .fold(0, |acc, manual| match is_unordered(rules, manual) {
+                true => {
+                    let mut sorted_manual = manual.clone();
+                    sorted_manual.sort_by(|a, b| match rules.get(a) {
+                        None => Ordering::Equal,
+                        Some(page_rules) => match page_rules.contains(b) {
+                            true => Ordering::Less,
+                            false => Ordering::Equal,
+                        },
+                    });
+                    acc + sorted_manual.get(sorted_manual.len() / 2).unwrap()
+                }
+                false => acc,
+            }))
+    }
+}
+
+/// Parses `X|Y` ordering rules.
+pub fn parse_rules(rules: &str) -> Result<HashMap<u32, Vec<u32>>> {
+    let mut rules_map: HashMap<u32, Vec<u32>> = HashMap::new();
+    let rule_re = Regex::new(r"(\d+)\|(\d+)")?;
+    for line in rules.lines() {
+        let captures = rule_re
+            .captures(line)
+            .with_context(|| format!("expected a rule like 47|53, got {line:?}"))?;
+        let (_, [page, before]) = captures.extract();
+        let (page, before) = (page.parse::<u32>()?, before.parse::<u32>()?);
+
+        match rules_map.entry(page) {
+            Entry::Vacant(entry) => {
+                entry.insert(vec![before]);
+            }
+            Entry::Occupied(mut entry) => {
+                entry.get_mut().push(before);
+            }
+        }
+    }
+
+    Ok(rules_map)
+}
+
+/// Parses the comma-separated page lists.
+pub fn parse_manuals(manuals: &str) -> Result<Vec<Vec<u32>>> {
+    manuals
+        .lines()
+        .map(|line| {
+            line.split(',')
+                .map(|d| Ok(d.parse::<u32>()?))
+                .collect::<Result<Vec<_>>>()
+                .with_context(|| format!("bad manual {line:?}"))
+        })
+        .collect()
+}
@@ -0,0 +1,384 @@
+use crate::simulation::{Condition, Debuggable, Simulation};
+use crate::{cancel, parse_with_coords, progress, recording, value, Alternative, Coord, Solution};
+use anyhow::*;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Formatter};
+
+pub struct Day06;
+
+/// Up, right, down and left, in the order the guard turns.
+pub const DIRECTIONS: &[Coord] = &[
+    Coord { x: 0, y: -1 },
+    Coord { x: 1, y: 0 },
+    Coord { x: 0, y: 1 },
+    Coord { x: -1, y: 0 },
+];
+
+/// Where the guard starts.
+pub fn find_guard(map: &HashMap<Coord, MapItem>) -> Result<Coord> {
+    map.iter()
+        .find(|(_, &item)| item == MapItem::Guard)
+        .map(|(&position, _)| position)
+        .context("no guard on the map")
+}
+
+/// Positions the guard visits before leaving the map. A guard that never
+/// leaves only stops when the runner cancels.
+pub fn part1_body(map: &HashMap<Coord, MapItem>, map_size: Coord) -> Result<HashSet<Coord>> {
+    walk(Patrol::new(Cow::Borrowed(map), map_size)?, true)
+}
+
+/// Steps the guard off the map, recording a frame after each step if `record`,
+/// and returns the positions it visited.
+fn walk(mut patrol: Patrol, record: bool) -> Result<HashSet<Coord>> {
+    while !patrol.done() {
+        cancel::check()?;
+        patrol.step()?;
+        if record {
+            recording::frame(
+                || format!("{} cells visited", patrol.positions().len()),
+                || {
+                    let path = |c| (0..4).any(|d| patrol.visited(c, d)).then_some('X');
+                    let Guard {
+                        position,
+                        direction,
+                    } = patrol.guard;
+                    draw(&patrol.map, patrol.map_size, path, (position, direction))
+                },
+            );
+        }
+    }
+    Ok(patrol.positions())
+}
+
+/// Draws the map as the puzzle does, with the guard facing its direction and
+/// `path` marking the cells it has been.
+fn draw(
+    map: &HashMap<Coord, MapItem>,
+    map_size: Coord,
+    path: impl Fn(Coord) -> Option<char>,
+    (guard, direction): (Coord, usize),
+) -> String {
+    (0..=map_size.y)
+        .map(|y| {
+            (0..=map_size.x)
+                .map(|x| {
+                    let c = Coord { x, y };
+                    match map.get(&c) {
+                        _ if c == guard => ['^', '>', 'v', '<'][direction],
+                        Some(MapItem::Obstruction) => '#',
+                        _ => path(c).unwrap_or('.'),
+                    }
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+/// Counts the single obstructions that would trap the guard in a loop.
+pub fn count_loops(map: &HashMap<Coord, MapItem>, map_size: Coord) -> Result<u32> {
+    let starting_position = find_guard(map)?;
+
+    let patrol = Patrol::starting_at(Cow::Borrowed(map), map_size, starting_position);
+    let mut obstruction_candidates = walk(patrol, false)?;
+    obstruction_candidates.remove(&starting_position);
+    value!(candidates = obstruction_candidates.len());
+    let total = obstruction_candidates.len() as u64;
+
+    obstruction_candidates
+        .iter()
+        .enumerate()
+        .try_fold(0, |acc, (done, &new_obstruction)| {
+            cancel::check()?;
+            progress::update(done as u64, total);
+            let mut patrol = Patrol::starting_at(Cow::Borrowed(map), map_size, starting_position)
+                .with_obstruction(new_obstruction);
+            while !patrol.done() && !patrol.revisiting() {
+                patrol.step()?;
+            }
+            let loops = patrol.revisiting();
+
+            recording::frame(
+                || {
+                    let outcome = if loops { "loops" } else { "leaves" };
+                    let Coord { x, y } = new_obstruction;
+                    format!("obstruction {}/{total} at {x},{y}: {outcome}", done + 1)
+                },
+                || {
+                    // The path as the puzzle draws it, with the new obstruction as `O`.
+                    let path = |c: Coord| {
+                        let through = |directions: [usize; 2]| {
+                            directions.iter().any(|&d| patrol.visited(c, d))
+                        };
+                        match (c == new_obstruction, through([0, 2]), through([1, 3])) {
+                            (true, _, _) => Some('O'),
+                            (_, true, true) => Some('+'),
+                            (_, true, false) => Some('|'),
+                            (_, false, true) => Some('-'),
+                            _ => None,
+                        }
+                    };
+                    draw(map, map_size, path, (starting_position, 0))
+                },
+            );
+            Ok(acc + u32::from(loops))
+        })
+}
+
+/// Where the guard is and which of [`DIRECTIONS`] it faces.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pub position: Coord,
+    pub direction: usize,
+}
+
+impl Debug for Guard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Coord { x, y } = self.position;
+        write!(f, "{x},{y} {}", ['^', '>', 'v', '<'][self.direction])
+    }
+}
+
+/// The guard's walk one step at a time, remembering every position and direction.
+pub struct Patrol<'a> {
+    map: Cow<'a, HashMap<Coord, MapItem>>,
+    map_size: Coord,
+    /// An extra obstruction on top of the map's, as part 2 tries.
+    obstruction: Option<Coord>,
+    guard: Guard,
+    visited: HashSet<Guard>,
+}
+
+impl<'a> Patrol<'a> {
+    pub fn new(map: Cow<'a, HashMap<Coord, MapItem>>, map_size: Coord) -> Result<Patrol<'a>> {
+        let position = find_guard(&map)?;
+        Ok(Patrol::starting_at(map, map_size, position))
+    }
+
+    /// A patrol from a guard position that's already known, saving a search of the map.
+    pub fn starting_at(
+        map: Cow<'a, HashMap<Coord, MapItem>>,
+        map_size: Coord,
+        position: Coord,
+    ) -> Patrol<'a> {
+        Patrol {
+            map,
+            map_size,
+            obstruction: None,
+            guard: Guard {
+                position,
+                direction: 0,
+            },
+            visited: HashSet::new(),
+        }
+    }
+
+    pub fn with_obstruction(self, obstruction: Coord) -> Patrol<'a> {
+        Patrol {
+            obstruction: Some(obstruction),
+            ..self
+        }
+    }
+
+    /// Whether the guard has been here facing this way before, i.e. is walking in a loop.
+    pub fn revisiting(&self) -> bool {
+        self.visited.contains(&self.guard)
+    }
+
+    /// Whether the guard left `position` facing `direction` at some point.
+    pub fn visited(&self, position: Coord, direction: usize) -> bool {
+        self.visited.contains(&Guard {
+            position,
+            direction,
+        })
+    }
+
+    /// The positions the guard has left so far.
+    pub fn positions(&self) -> HashSet<Coord> {
+        self.visited.iter().map(|guard| guard.position).collect()
+    }
+}
+
+impl Simulation for Patrol<'_> {
+    type State = Guard;
+
+    fn state(&self) -> &Guard {
+        &self.guard
+    }
+
+    fn step(&mut self) -> Result<()> {
+        self.visited.insert(self.guard);
+        let Guard {
+            position,
+            direction,
+        } = self.guard;
+        let next_position = position + DIRECTIONS[direction];
+        let blocked = self.obstruction == Some(next_position)
+            || self.map.get(&next_position) == Some(&MapItem::Obstruction);
+        match blocked {
+            true => self.guard.direction = (direction + 1) % 4,
+            false => self.guard.position = next_position,
+        }
+        Ok(())
+    }
+
+    fn done(&self) -> bool {
+        !self.guard.position.in_map(self.map_size)
+    }
+}
+
+/// Where the guard ends up walking from each cell in each direction: the cell
+/// before the next obstruction, or `None` if the guard walks off the map.
+pub struct JumpTable {
+    map_size: Coord,
+    stops: Vec<Option<Coord>>,
+}
+
+impl JumpTable {
+    pub fn new(map: &HashMap<Coord, MapItem>, map_size: Coord) -> JumpTable {
+        let width = map_size.x as usize + 1;
+        let cells = width * (map_size.y as usize + 1);
+        let coords = (0..cells)
+            .map(|i| Coord {
+                x: (i % width) as i32,
+                y: (i / width) as i32,
+            })
+            .collect::<Vec<_>>();
+        let mut table = JumpTable {
+            map_size,
+            stops: vec![None; cells * 4],
+        };
+
+        for (direction, &step) in DIRECTIONS.iter().enumerate() {
+            // Cells furthest along the direction first, so each cell's next one is already known.
+            let mut order = coords.clone();
+            order.sort_by_key(|c| -(c.x * step.x + c.y * step.y));
+            for position in order {
+                let next = position + step;
+                let stop = match map.get(&next) {
+                    _ if !next.in_rect(Coord::zero(), map_size) => None,
+                    Some(MapItem::Obstruction) => Some(position),
+                    _ => table.stop(next, direction),
+                };
+                let index = table.index(position, direction);
+                table.stops[index] = stop;
+            }
+        }
+
+        table
+    }
+
+    fn index(&self, position: Coord, direction: usize) -> usize {
+        let width = self.map_size.x as usize + 1;
+        (position.y as usize * width + position.x as usize) * 4 + direction
+    }
+
+    pub fn stop(&self, position: Coord, direction: usize) -> Option<Coord> {
+        self.stops[self.index(position, direction)]
+    }
+}
+
+/// Counts loops like [`count_loops`], but jumps from obstruction to obstruction
+/// instead of stepping through every cell.
+pub fn count_loops_jumping(map: &HashMap<Coord, MapItem>, map_size: Coord) -> Result<u32> {
+    let starting_position = find_guard(map)?;
+    let table = JumpTable::new(map, map_size);
+
+    let patrol = Patrol::starting_at(Cow::Borrowed(map), map_size, starting_position);
+    let mut obstruction_candidates = walk(patrol, false)?;
+    obstruction_candidates.remove(&starting_position);
+    let total = obstruction_candidates.len() as u64;
+
+    obstruction_candidates
+        .iter()
+        .enumerate()
+        .try_fold(0, |acc, (done, &new_obstruction)| {
+            cancel::check()?;
+            progress::update(done as u64, total);
+            let mut position = starting_position;
+            let mut direction = 0;
+            let mut turns = HashSet::new();
+
+            loop {
+                let step = DIRECTIONS[direction];
+                let along = |c: Coord| (c - position).x * step.x + (c - position).y * step.y;
+                // The new obstruction only matters if it's straight ahead.
+                let ahead = along(new_obstruction);
+                let hits_new = ahead > 0 && position + step * ahead == new_obstruction;
+                let blocked = new_obstruction - step;
+                position = match table.stop(position, direction) {
+                    Some(stop) if hits_new && along(blocked) < along(stop) => blocked,
+                    Some(stop) => stop,
+                    None if hits_new => blocked,
+                    None => return Ok(acc),
+                };
+                direction = (direction + 1) % 4;
+                if !turns.insert((position, direction)) {
+                    return Ok(acc + 1);
+                }
+            }
+        })
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MapItem {
+    Guard,
+    Obstruction,
+}
+
+impl MapItem {
+    pub fn parse(c: &char) -> Option<MapItem> {
+        match c {
+            '#' => Some(MapItem::Obstruction),
+            '^' => Some(MapItem::Guard),
+            _ => None,
+        }
+    }
+}
+
+impl Solution for Day06 {
+    type Input = (Coord, HashMap<Coord, MapItem>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (map_size, map) = parse_with_coords(input, MapItem::parse);
+        let guards = map.values().filter(|&&item| item == MapItem::Guard).count();
+        ensure!(guards == 1, "expected one guard on the map, found {guards}");
+        Ok((map_size, map))
+    }
+
+    fn part1((map_size, map): &Self::Input) -> Result<u32> {
+        Ok(part1_body(map, *map_size)?.len() as u32)
+    }
+
+    fn part2((map_size, map): &Self::Input) -> Result<u32> {
+        count_loops(map, *map_size)
+    }
+
+    fn alternatives() -> Vec<Alternative<Self>> {
+        vec![Alternative::Part2(
+            "jump table",
+            |(map_size, map): &(Coord, HashMap<Coord, MapItem>)| {
+                count_loops_jumping(map, *map_size)
+            },
+        )]
+    }
+}
+
+impl Debuggable for Day06 {
+    type Simulation = Patrol<'static>;
+
+    fn simulation((map_size, map): &Self::Input, part: u8) -> Result<Patrol<'static>> {
+        match part {
+            1 => Patrol::new(Cow::Owned(map.clone()), *map_size),
+            _ => bail!("only part 1's patrol can be debugged; part 2 tries every obstruction"),
+        }
+    }
+
+    fn breakpoints() -> Vec<(&'static str, Condition<Patrol<'static>>)> {
+        vec![("revisit", Patrol::revisiting)]
+    }
+}
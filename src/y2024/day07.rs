@@ -0,0 +1,149 @@
+use crate::stream::{add_record, Records, Streaming};
+use crate::{value, Solution};
+use anyhow::*;
+use itertools::Itertools;
+
+pub struct Day07;
+
+#[derive(Debug, Copy, Clone)]
+pub enum Operator {
+    Add,
+    Multiply,
+    Concat,
+}
+
+/// Every sequence of `n` items picked from `from`, with repetition.
+pub fn variations<T>(n: usize, from: &[T]) -> Vec<Vec<&T>> {
+    std::iter::repeat_n(from.iter(), n)
+        .multi_cartesian_product()
+        .collect::<Vec<Vec<&T>>>()
+}
+
+/// Sums the test values of the calibrations that `operators` can make true.
+/// `match_fn` returns `None` on overflow, which can't match any test value.
+pub fn fold<F>(calibrations: &[(u64, Vec<u64>)], operators: &[Operator], match_fn: F) -> u64
+where
+    F: Fn(u64, u64, &Operator) -> Option<u64>,
+{
+    calibrations.iter().fold(0, |acc, (result, parts)| {
+        if variations(parts.len() - 1, operators).iter().any(|op| {
+            let sum = parts[1..]
+                .iter()
+                .zip(op)
+                .try_fold(parts[0], |acc, (part, op)| match_fn(acc, *part, op));
+
+            sum == Some(*result)
+        }) {
+            acc + result
+        } else {
+            acc
+        }
+    })
+}
+
+/// Slow but obviously correct versions, for property tests.
+pub mod reference {
+    /// Whether `+`, `*` and optionally `||`, applied left to right, can turn `parts` into `target`.
+    pub fn solvable(target: u64, parts: &[u64], concat: bool) -> bool {
+        fn go(target: u64, acc: u64, rest: &[u64], concat: bool) -> bool {
+            match rest {
+                [] => acc == target,
+                [next, rest @ ..] => {
+                    go(target, acc + next, rest, concat)
+                        || go(target, acc * next, rest, concat)
+                        || (concat
+                            && go(
+                                target,
+                                format!("{acc}{next}").parse().unwrap(),
+                                rest,
+                                concat,
+                            ))
+                }
+            }
+        }
+
+        match parts {
+            [] => false,
+            [first, rest @ ..] => go(target, *first, rest, concat),
+        }
+    }
+
+    pub fn total(calibrations: &[(u64, Vec<u64>)], concat: bool) -> u64 {
+        calibrations
+            .iter()
+            .filter(|(target, parts)| solvable(*target, parts, concat))
+            .map(|(target, _)| target)
+            .sum()
+    }
+}
+
+impl Solution for Day07 {
+    type Input = Vec<(u64, Vec<u64>)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let calibrations = parse_calibrations(input)?;
+        value!(calibrations = calibrations.len());
+        Ok(calibrations)
+    }
+
+    fn part1(calibrations: &Self::Input) -> Result<u64> {
+        let operators = [Operator::Add, Operator::Multiply];
+
+        Ok(fold(calibrations, &operators, |acc, part, op| match op {
+            Operator::Add => acc.checked_add(part),
+            Operator::Multiply => acc.checked_mul(part),
+            Operator::Concat => panic!("concat operator should not occur for part1"),
+        }))
+    }
+
+    fn part2(calibrations: &Self::Input) -> Result<u64> {
+        let operators = [Operator::Add, Operator::Multiply, Operator::Concat];
+
+        Ok(fold(calibrations, &operators, |acc, part, op| match op {
+            Operator::Add => acc.checked_add(part),
+            Operator::Multiply => acc.checked_mul(part),
+            Operator::Concat => {
+                let digits = part.checked_ilog10().unwrap_or(0) + 1;
+                acc.checked_mul(10u64.checked_pow(digits)?)?
+                    .checked_add(part)
+            }
+        }))
+    }
+}
+
+/// Parses `test: a b c` lines.
+pub fn parse_calibrations(input: &str) -> Result<Vec<(u64, Vec<u64>)>> {
+    input
+        .lines()
+        .map(|line| {
+            let (result, parts) = line
+                .split_once(": ")
+                .with_context(|| format!("expected `test: a b c`, got {line:?}"))?;
+            let parts = parts
+                .split_whitespace()
+                .map(|p| Ok(p.parse::<u64>()?))
+                .collect::<Result<Vec<_>>>()?;
+            ensure!(
+                !parts.is_empty(),
+                "no numbers after the test value in {line:?}"
+            );
+
+            Ok((result.parse::<u64>()?, parts))
+        })
+        .collect()
+}
+
+impl Streaming for Day07 {
+    const RECORDS: Records = Records::Lines;
+    type Totals = (u64, u64);
+
+    fn add(totals: &mut (u64, u64), record: &str) -> Result<()> {
+        add_record::<Day07>(totals, record)
+    }
+
+    fn answers(totals: (u64, u64)) -> Result<(u64, u64)> {
+        Ok(totals)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{parse_with_coords, value, Coord, Solution};
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day08;
+
+/// Returns the map size and each frequency's antenna positions.
+pub fn parse_antennas(input: &str) -> (Coord, HashMap<char, Vec<Coord>>) {
+    let (map_size, antenna_map) =
+        parse_with_coords(input, |c| if c == &'.' { None } else { Some(*c) });
+
+    let mut antennas = HashMap::new();
+    antenna_map
+        .iter()
+        .for_each(|(&coord, &id)| match antennas.entry(id) {
+            Entry::Vacant(e) => {
+                e.insert(vec![coord]);
+            }
+            Entry::Occupied(mut e) => {
+                e.get_mut().push(coord);
+            }
+        });
+
+    (map_size, antennas)
+}
+
+impl Solution for Day08 {
+    type Input = (Coord, HashMap<char, Vec<Coord>>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_antennas(input))
+    }
+
+    fn part1((map_size, antennas): &Self::Input) -> Result<u64> {
+        let map_size = *map_size;
+        value!(frequencies = antennas.len());
+
+        let mut resonances = HashSet::new();
+        antennas.values().for_each(|coords| {
+            coords.iter().permutations(2).for_each(|coord_pair| {
+                let &a = coord_pair[0];
+                let &b = coord_pair[1];
+                let dist = a - b;
+                if (a + dist).in_rect(Coord { x: 0, y: 0 }, map_size) {
+                    resonances.insert(a + dist);
+                };
+                if (b - dist).in_rect(Coord { x: 0, y: 0 }, map_size) {
+                    resonances.insert(b - dist);
+                };
+            })
+        });
+
+        Ok(resonances.len() as u64)
+    }
+
+    fn part2((map_size, antennas): &Self::Input) -> Result<u64> {
+        let map_size = *map_size;
+
+        let mut resonances = HashSet::new();
+        antennas.values().for_each(|coords| {
+            coords.iter().permutations(2).for_each(|coord_pair| {
+                let &a = coord_pair[0];
+                let &b = coord_pair[1];
+                let dist = a - b;
+                let mut i = 0;
+                while (a + dist * i).in_rect(Coord { x: 0, y: 0 }, map_size) {
+                    resonances.insert(a + dist * i);
+                    i += 1;
+                }
+                let mut i = 0;
+                while (b - dist * i).in_rect(Coord { x: 0, y: 0 }, map_size) {
+                    resonances.insert(b - dist * i);
+                    i += 1;
+                }
+            })
+        });
+
+        Ok(resonances.len() as u64)
+    }
+}
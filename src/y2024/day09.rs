@@ -0,0 +1,190 @@
+use crate::{value, Solution};
+use anyhow::*;
+use std::collections::VecDeque;
+
+pub struct Day09;
+
+#[derive(Debug)]
+pub enum DiskEntry {
+    Free { len: u32 },
+    File { len: u32, id: usize },
+}
+
+/// Expands the dense disk map into alternating files and free space.
+pub fn disk_entries(disk_map: &[u32]) -> VecDeque<DiskEntry> {
+    disk_map
+        .iter()
+        .enumerate()
+        .map(|(idx, &len)| match idx % 2 {
+            0 => DiskEntry::File { len, id: idx / 2 },
+            _ => DiskEntry::Free { len },
+        })
+        .collect()
+}
+
+/// Slow but obviously correct versions, for property tests.
+pub mod reference {
+    /// One slot per block, holding the file id or `None` for free space.
+    fn blocks(disk_map: &[u32]) -> Vec<Option<usize>> {
+        disk_map
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, &len)| {
+                let id = (idx % 2 == 0).then_some(idx / 2);
+                std::iter::repeat_n(id, len as usize)
+            })
+            .collect()
+    }
+
+    fn checksum(blocks: &[Option<usize>]) -> u64 {
+        blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(i, id)| id.map(|id| (i * id) as u64))
+            .sum()
+    }
+
+    /// Moves the last file block into the first free block until no gaps are left.
+    pub fn compact_blocks(disk_map: &[u32]) -> u64 {
+        let mut blocks = blocks(disk_map);
+        while let Some(free) = blocks.iter().position(Option::is_none) {
+            let last = blocks.iter().rposition(Option::is_some);
+            match last {
+                Some(last) if last > free => blocks.swap(free, last),
+                _ => break,
+            }
+        }
+        checksum(&blocks)
+    }
+
+    /// Tries each file once, highest id first, moving it whole into the leftmost gap before it.
+    pub fn compact_files(disk_map: &[u32]) -> u64 {
+        let mut blocks = blocks(disk_map);
+        let files = disk_map.len().div_ceil(2);
+        for id in (0..files).rev() {
+            let Some(start) = blocks.iter().position(|&b| b == Some(id)) else {
+                continue;
+            };
+            let len = blocks.iter().filter(|&&b| b == Some(id)).count();
+            let gap = (0..start)
+                .take_while(|&i| i + len <= start)
+                .find(|&i| blocks[i..i + len].iter().all(Option::is_none));
+            if let Some(gap) = gap {
+                (0..len).for_each(|i| blocks.swap(gap + i, start + i));
+            }
+        }
+        checksum(&blocks)
+    }
+}
+
+impl Solution for Day09 {
+    type Input = Vec<u32>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .chars()
+            .map(|c| {
+                c.to_digit(10)
+                    .with_context(|| format!("invalid digit {c:?}"))
+            })
+            .collect()
+    }
+
+    fn part1(disk_map: &Self::Input) -> Result<u64> {
+        let mut entries = disk_entries(disk_map);
+
+        let mut disk_idx = 0u64;
+        let mut checksum = 0;
+
+        while !entries.is_empty() {
+            let front = entries.pop_front();
+
+            match front {
+                Some(DiskEntry::File { mut len, id }) => {
+                    while len > 0 {
+                        checksum += disk_idx * id as u64;
+                        disk_idx += 1;
+                        len -= 1;
+                    }
+                }
+                Some(DiskEntry::Free { len: mut len_free }) => {
+                    while len_free > 0 {
+                        match entries.pop_back() {
+                            Some(DiskEntry::Free { len: _len }) => {}
+                            Some(DiskEntry::File {
+                                len: mut len_file,
+                                id,
+                            }) => {
+                                while len_file > 0 && len_free > 0 {
+                                    checksum += disk_idx * id as u64;
+                                    disk_idx += 1;
+                                    len_file -= 1;
+                                    len_free -= 1;
+                                }
+                                if len_file > 0 {
+                                    entries.push_back(DiskEntry::File { len: len_file, id });
+                                }
+                            }
+                            None => {
+                                break;
+                            }
+                        }
+                    }
+                }
+                None => break,
+            }
+        }
+
+        Ok(checksum)
+    }
+
+    fn part2(disk_map: &Self::Input) -> Result<u64> {
+        let mut entries = disk_entries(disk_map);
+        value!(entries = entries.len());
+
+        let mut tail = VecDeque::new();
+
+        while !entries.is_empty() {
+            match entries.pop_back() {
+                Some(DiskEntry::Free { len }) => {
+                    tail.push_front(DiskEntry::Free { len });
+                }
+                Some(DiskEntry::File { id, len: len_file }) => {
+                    match entries.iter().position(|e| match e {
+                        DiskEntry::Free { len: len_free } => *len_free >= len_file,
+                        _ => false,
+                    }) {
+                        Some(free_idx) => {
+                            if let Some(DiskEntry::Free { len: len_free }) =
+                                entries.get_mut(free_idx)
+                            {
+                                *len_free -= len_file;
+                            }
+                            entries.insert(free_idx, DiskEntry::File { id, len: len_file });
+                            tail.push_front(DiskEntry::Free { len: len_file });
+                        }
+                        _ => tail.push_front(DiskEntry::File { id, len: len_file }),
+                    }
+                }
+                None => {
+                    break;
+                }
+            }
+        }
+
+        Ok(tail
+            .iter()
+            .fold((0u64, 0u64), |(disk_index, checksum), entry| match *entry {
+                DiskEntry::Free { len } => (disk_index + len as u64, checksum),
+                DiskEntry::File { len, id } => {
+                    let add_checksum: u64 = (disk_index..disk_index + len as u64)
+                        .map(|i| i * id as u64)
+                        .sum();
+                    (disk_index + len as u64, checksum + add_checksum)
+                }
+            })
+            .1)
+    }
+}
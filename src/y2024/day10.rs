@@ -0,0 +1,136 @@
+use crate::{parse_with_coords, value, Coord, Solution};
+use anyhow::*;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub struct Day10;
+
+pub const DIRECTIONS: &[Coord] = &[
+    Coord { x: 0, y: -1 },
+    Coord { x: 1, y: 0 },
+    Coord { x: 0, y: 1 },
+    Coord { x: -1, y: 0 },
+];
+
+impl Solution for Day10 {
+    type Input = HashMap<Coord, u32>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_with_coords(input, |&c| c.to_digit(10)).1)
+    }
+
+    fn part1(trail_map: &Self::Input) -> Result<u64> {
+        let top_coords = trail_map
+            .iter()
+            .filter_map(|(coord, c)| match c {
+                9 => Some(*coord),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut accessible: HashMap<Coord, u64> = HashMap::new();
+
+        top_coords.iter().for_each(|&top| {
+            let mut visited = HashSet::new();
+            visited.insert(top);
+
+            let mut candidates = VecDeque::new();
+            DIRECTIONS.iter().for_each(|dir| {
+                let next_position = top + *dir;
+                if let Some(&next_height) = trail_map.get(&next_position) {
+                    if 9u32.checked_sub(next_height).unwrap_or(99) == 1 {
+                        candidates.push_back((next_position, next_height));
+                    };
+                }
+            });
+            while let Some((position, height)) = candidates.pop_front() {
+                if visited.contains(&position) {
+                    continue;
+                }
+                accessible
+                    .entry(position)
+                    .and_modify(|v| *v += 1)
+                    .or_insert(1);
+                visited.insert(position);
+                DIRECTIONS.iter().for_each(|dir| {
+                    let next_position = position + *dir;
+                    if let Some(&next_height) = trail_map.get(&next_position) {
+                        if height.checked_sub(next_height).unwrap_or(99) == 1 {
+                            candidates.push_back((next_position, next_height));
+                        };
+                    }
+                });
+            }
+        });
+
+        let trailheads = trail_map
+            .iter()
+            .filter_map(|(coord, c)| match c {
+                0 => Some(*coord),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        value!(trailheads = trailheads.len(), tops = top_coords.len());
+
+        Ok(trailheads
+            .iter()
+            .fold(0, |acc, coord| match accessible.get(coord) {
+                Some(n) => acc + n,
+                None => acc,
+            }))
+    }
+
+    fn part2(trail_map: &Self::Input) -> Result<u64> {
+        let top_coords = trail_map
+            .iter()
+            .filter_map(|(coord, c)| match c {
+                9 => Some(*coord),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut accessible: HashMap<Coord, u64> = HashMap::new();
+
+        top_coords.iter().for_each(|&top| {
+            let mut candidates = VecDeque::new();
+            DIRECTIONS.iter().for_each(|dir| {
+                let next_position = top + *dir;
+                if let Some(&next_height) = trail_map.get(&next_position) {
+                    if 9u32.checked_sub(next_height).unwrap_or(99) == 1 {
+                        candidates.push_back((next_position, next_height));
+                    };
+                }
+            });
+            while let Some((position, height)) = candidates.pop_front() {
+                accessible
+                    .entry(position)
+                    .and_modify(|v| *v += 1)
+                    .or_insert(1);
+                DIRECTIONS.iter().for_each(|dir| {
+                    let next_position = position + *dir;
+                    if let Some(&next_height) = trail_map.get(&next_position) {
+                        if height.checked_sub(next_height).unwrap_or(99) == 1 {
+                            candidates.push_back((next_position, next_height));
+                        };
+                    }
+                });
+            }
+        });
+
+        let trailheads = trail_map
+            .iter()
+            .filter_map(|(coord, c)| match c {
+                0 => Some(*coord),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        Ok(trailheads
+            .iter()
+            .fold(0, |acc, coord| match accessible.get(coord) {
+                Some(n) => acc + n,
+                None => acc,
+            }))
+    }
+}
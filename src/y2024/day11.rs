@@ -0,0 +1,147 @@
+use crate::{value, Alternative, Solution};
+use anyhow::*;
+use std::collections::HashMap;
+
+pub struct Day11;
+
+/// What a single stone turns into after one blink.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Blink {
+    Single(u64),
+    Split(u64, u64),
+}
+
+pub fn blink(stone: u64) -> Blink {
+    let n_digits = stone.checked_ilog10().unwrap_or(0) + 1;
+    match (stone, n_digits.is_multiple_of(2)) {
+        (0, _) => Blink::Single(1),
+        (stone, true) => {
+            let middle = 10u64.pow(n_digits / 2);
+            Blink::Split(stone / middle, stone % middle)
+        }
+        _ => Blink::Single(stone * 2024),
+    }
+}
+
+#[test]
+fn test_blink() {
+    assert_eq!(blink(0), Blink::Single(1));
+    assert_eq!(blink(1), Blink::Single(2024));
+    assert_eq!(blink(2024), Blink::Split(20, 24));
+}
+
+/// Counts the stones `stone` becomes after `remaining_blinks`, memoised in `memo`.
+pub fn step(stone: u64, remaining_blinks: usize, memo: &mut HashMap<(u64, usize), u64>) -> u64 {
+    if remaining_blinks == 0 {
+        return 1;
+    }
+    if let Some(&n) = memo.get(&(stone, remaining_blinks)) {
+        return n;
+    }
+    let res = match blink(stone) {
+        Blink::Single(new_stone) => step(new_stone, remaining_blinks - 1, memo),
+        Blink::Split(left, right) => {
+            step(left, remaining_blinks - 1, memo) + step(right, remaining_blinks - 1, memo)
+        }
+    };
+    memo.insert((stone, remaining_blinks), res);
+    res
+}
+
+/// Counts the stones after `blinks`, sharing one memo across all stones.
+pub fn count_stones(stones: &[u64], blinks: usize) -> u64 {
+    let mut memo: HashMap<(u64, usize), u64> = HashMap::new();
+
+    let count = stones
+        .iter()
+        .fold(0, |acc, &stone| acc + step(stone, blinks, &mut memo));
+    value!(memo_entries = memo.len());
+
+    count
+}
+
+/// Counts the stones after `blinks` by blinking a histogram of the numbers on
+/// them, since equal stones always turn into equal stones.
+pub fn count_stones_histogram(stones: &[u64], blinks: usize) -> u64 {
+    let mut histogram: HashMap<u64, u64> = HashMap::new();
+    stones
+        .iter()
+        .for_each(|&stone| *histogram.entry(stone).or_default() += 1);
+
+    for _ in 0..blinks {
+        let mut next = HashMap::with_capacity(histogram.len());
+        for (stone, count) in histogram {
+            match blink(stone) {
+                Blink::Single(stone) => *next.entry(stone).or_default() += count,
+                Blink::Split(left, right) => {
+                    *next.entry(left).or_default() += count;
+                    *next.entry(right).or_default() += count;
+                }
+            }
+        }
+        histogram = next;
+    }
+
+    histogram.values().sum()
+}
+
+/// Slow but obviously correct versions, for property tests.
+pub mod reference {
+    use super::{blink, Blink};
+
+    /// Blinks the whole row `blinks` times and counts what's left.
+    pub fn count_stones(stones: &[u64], blinks: usize) -> u64 {
+        let mut row = stones.to_vec();
+        for _ in 0..blinks {
+            row = row
+                .into_iter()
+                .flat_map(|stone| match blink(stone) {
+                    Blink::Single(stone) => vec![stone],
+                    Blink::Split(left, right) => vec![left, right],
+                })
+                .collect();
+        }
+        row.len() as u64
+    }
+}
+
+/// Stones must be engraved with less than this. A stone with an odd number of
+/// digits is multiplied by 2024 at most twice in a row before it splits, so
+/// up to 11 digits never grows past 18.
+pub const MAX_STONE: u64 = 1_000_000_000_000;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let stones = input
+            .split_whitespace()
+            .map(|s| s.parse::<u64>())
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(stone) = stones.iter().find(|&&stone| stone >= MAX_STONE) {
+            bail!("stone {stone} is too big, expected less than {MAX_STONE}");
+        }
+        Ok(stones)
+    }
+
+    fn part1(stones: &Self::Input) -> Result<u64> {
+        Ok(count_stones(stones, 25))
+    }
+
+    fn part2(stones: &Self::Input) -> Result<u64> {
+        Ok(count_stones(stones, 75))
+    }
+
+    fn alternatives() -> Vec<Alternative<Self>> {
+        vec![
+            Alternative::Part1("histogram", |stones: &Vec<u64>| {
+                Ok(count_stones_histogram(stones, 25))
+            }),
+            Alternative::Part2("histogram", |stones: &Vec<u64>| {
+                Ok(count_stones_histogram(stones, 75))
+            }),
+        ]
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{parse_with_coords, Coord, Solution};
+use anyhow::*;
+use log::trace;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub struct Day12;
+
+pub const DIRECTIONS: &[Coord] = &[
+    Coord { x: 0, y: -1 },
+    Coord { x: 1, y: 0 },
+    Coord { x: 0, y: 1 },
+    Coord { x: -1, y: 0 },
+];
+
+impl Solution for Day12 {
+    type Input = HashMap<Coord, char>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_with_coords(input, |c| Some(*c)).1)
+    }
+
+    fn part1(garden_map: &Self::Input) -> Result<u64> {
+        let mut counted = HashSet::new();
+
+        let coords = garden_map.keys().cloned().collect::<Vec<_>>();
+
+        Ok(coords.iter().fold(0, |acc, &c| {
+            if counted.contains(&c) {
+                return acc;
+            };
+
+            let label = garden_map[&c];
+
+            let mut area = 0;
+            let mut perimeter = 0;
+            let mut patch_candidates = VecDeque::new();
+            patch_candidates.push_back(c);
+
+            while let Some(candidate) = patch_candidates.pop_front() {
+                area += 1;
+                counted.insert(candidate);
+                let mut candidate_perimeter = 4;
+                DIRECTIONS.iter().for_each(|dir| {
+                    let next = candidate + *dir;
+                    if garden_map.get(&next) == Some(&label) {
+                        candidate_perimeter -= 1;
+                        if !counted.contains(&next) && !patch_candidates.contains(&next) {
+                            patch_candidates.push_back(next);
+                        }
+                    }
+                });
+                perimeter += candidate_perimeter;
+            }
+            trace!("region {label} at {c:?}: area {area}, perimeter {perimeter}");
+
+            acc + area * perimeter
+        }))
+    }
+
+    fn part2(garden_map: &Self::Input) -> Result<u64> {
+        let mut counted = HashSet::new();
+
+        let coords = garden_map.keys().cloned().collect::<Vec<_>>();
+
+        Ok(coords.iter().fold(0, |acc, &c| {
+            if counted.contains(&c) {
+                return acc;
+            };
+
+            let label = garden_map[&c];
+
+            let mut area = 0;
+            let mut left = HashSet::new();
+            let mut right = HashSet::new();
+            let mut top = HashSet::new();
+            let mut bottom = HashSet::new();
+            let mut patch_candidates = VecDeque::new();
+            patch_candidates.push_back(c);
+
+            while let Some(candidate) = patch_candidates.pop_front() {
+                area += 1;
+                counted.insert(candidate);
+                if right
+                    .take(&Coord {
+                        x: candidate.x - 1,
+                        y: candidate.y,
+                    })
+                    .is_none()
+                {
+                    left.insert(candidate);
+                }
+                if left
+                    .take(&Coord {
+                        x: candidate.x + 1,
+                        y: candidate.y,
+                    })
+                    .is_none()
+                {
+                    right.insert(candidate);
+                }
+                if bottom
+                    .take(&Coord {
+                        x: candidate.x,
+                        y: candidate.y - 1,
+                    })
+                    .is_none()
+                {
+                    top.insert(candidate);
+                }
+                if top
+                    .take(&Coord {
+                        x: candidate.x,
+                        y: candidate.y + 1,
+                    })
+                    .is_none()
+                {
+                    bottom.insert(candidate);
+                }
+                DIRECTIONS.iter().for_each(|dir| {
+                    let next = candidate + *dir;
+                    if garden_map.get(&next) == Some(&label)
+                        && !counted.contains(&next)
+                        && !patch_candidates.contains(&next)
+                    {
+                        patch_candidates.push_back(next);
+                    }
+                });
+            }
+
+            let mut sides = 0;
+            [left, right].iter_mut().for_each(|set| {
+                while !set.is_empty() {
+                    let first = *set.iter().next().unwrap();
+                    let mut i = 0;
+                    while set
+                        .take(&Coord {
+                            x: first.x,
+                            y: first.y + i,
+                        })
+                        .is_some()
+                    {
+                        i += 1;
+                    }
+                    let mut i = 1;
+                    while set
+                        .take(&Coord {
+                            x: first.x,
+                            y: first.y - i,
+                        })
+                        .is_some()
+                    {
+                        i += 1;
+                    }
+                    sides += 1;
+                }
+            });
+
+            [top, bottom].iter_mut().for_each(|set| {
+                while !set.is_empty() {
+                    let first = *set.iter().next().unwrap();
+                    let mut i = 0;
+                    while set
+                        .take(&Coord {
+                            x: first.x + i,
+                            y: first.y,
+                        })
+                        .is_some()
+                    {
+                        i += 1;
+                    }
+                    let mut i = 1;
+                    while set
+                        .take(&Coord {
+                            x: first.x - i,
+                            y: first.y,
+                        })
+                        .is_some()
+                    {
+                        i += 1;
+                    }
+                    sides += 1;
+                }
+            });
+
+            acc + area * sides
+        }))
+    }
+}
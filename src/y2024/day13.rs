@@ -0,0 +1,205 @@
+use crate::stream::{add_record, Records, Streaming};
+use crate::{value, Solution};
+use anyhow::*;
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::newline;
+use nom::combinator::opt;
+use nom::multi::separated_list1;
+use nom::sequence::{separated_pair, terminated};
+use nom::IResult;
+
+pub struct Day13;
+
+/// The largest button movement or prize coordinate accepted, well past the
+/// puzzle's. It keeps part 2's Cramer's rule and token counts within 64 bits.
+pub const MAX_VALUE: i64 = 100_000;
+
+#[derive(Debug)]
+pub struct Machine {
+    /// Button A's and button B's `[x, y]` movements.
+    pub coefficients: [[i64; 2]; 2],
+    /// The prize position.
+    pub constants: [i64; 2],
+}
+
+impl Machine {
+    pub fn determinant(&self) -> i64 {
+        self.coefficients[0][0] * self.coefficients[1][1]
+            - self.coefficients[1][0] * self.coefficients[0][1]
+    }
+
+    /// Solves for the button presses with Cramer's rule, if there is a non-negative whole-number solution.
+    pub fn solve(&self) -> Option<[i64; 2]> {
+        match self.determinant() {
+            0 => None,
+            det => {
+                let a = self.constants[0] * self.coefficients[1][1]
+                    - self.constants[1] * self.coefficients[1][0];
+                let b = self.coefficients[0][0] * self.constants[1]
+                    - self.coefficients[0][1] * self.constants[0];
+                if a % det != 0 || b % det != 0 || a / det < 0 || b / det < 0 {
+                    None
+                } else {
+                    Some([a / det, b / det])
+                }
+            }
+        }
+    }
+}
+
+/// Slow but obviously correct versions, for property tests.
+pub mod reference {
+    use super::Machine;
+
+    /// The fewest tokens that win the prize with at most `max_presses` presses per button.
+    pub fn cost(machine: &Machine, max_presses: i64) -> Option<i64> {
+        let [[ax, ay], [bx, by]] = machine.coefficients;
+        let [px, py] = machine.constants;
+
+        (0..=max_presses)
+            .flat_map(|a| (0..=max_presses).map(move |b| (a, b)))
+            .filter(|&(a, b)| a * ax + b * bx == px && a * ay + b * by == py)
+            .map(|(a, b)| 3 * a + b)
+            .min()
+    }
+}
+
+#[test]
+fn test_solve_les() {
+    assert_eq!(
+        Machine {
+            coefficients: [[94, 34], [22, 67]],
+            constants: [8400, 5400]
+        }
+        .solve(),
+        Some([80, 40])
+    );
+    assert_eq!(
+        Machine {
+            coefficients: [[26, 66], [67, 21]],
+            constants: [12748, 12176]
+        }
+        .solve(),
+        None
+    );
+    assert_eq!(
+        Machine {
+            coefficients: [[17, 86], [84, 37]],
+            constants: [7870, 6450]
+        }
+        .solve(),
+        Some([38, 86])
+    );
+    assert_eq!(
+        Machine {
+            coefficients: [[69, 23], [27, 71]],
+            constants: [18641, 10279]
+        }
+        .solve(),
+        None
+    );
+}
+
+pub fn parse_machine(input: &str) -> IResult<&str, Machine> {
+    let (rem, button_a) = terminated(parse_button, newline)(input)?;
+    let (rem, button_b) = terminated(parse_button, newline)(rem)?;
+    let (rem, prize) = terminated(parse_prize, opt(newline))(rem)?;
+
+    IResult::Ok((
+        rem,
+        Machine {
+            coefficients: [[button_a.0, button_a.1], [button_b.0, button_b.1]],
+            constants: [prize.0, prize.1],
+        },
+    ))
+}
+
+pub fn parse_prize(input: &str) -> IResult<&str, (i64, i64)> {
+    let (remaining, _) = tag("Prize: ")(input)?;
+    separated_pair(parse_val, tag(", "), parse_val)(remaining)
+}
+pub fn parse_button(input: &str) -> IResult<&str, (i64, i64)> {
+    let (remaining, _) = alt((tag("Button A: "), tag("Button B: ")))(input)?;
+    separated_pair(parse_val, tag(", "), parse_val)(remaining)
+}
+
+pub fn parse_val(input: &str) -> IResult<&str, i64> {
+    let (remaining, _) = alt((tag("X+"), tag("Y+"), tag("X="), tag("Y=")))(input)?;
+    nom::character::complete::i64(remaining)
+}
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rest, machines) =
+            separated_list1(newline, parse_machine)(input).map_err(|e| e.to_owned())?;
+        ensure!(
+            matches!(rest, "" | "\n"),
+            "unexpected {rest:?} after the last machine"
+        );
+        for machine in &machines {
+            let mut values = machine
+                .coefficients
+                .iter()
+                .flatten()
+                .chain(&machine.constants);
+            ensure!(
+                values.all(|v| (0..=MAX_VALUE).contains(v)),
+                "{machine:?} has values outside 0..={MAX_VALUE}"
+            );
+        }
+        Ok(machines)
+    }
+
+    fn part1(machines: &Self::Input) -> Result<u64> {
+        value!(machines = machines.len());
+        machines
+            .iter()
+            .try_fold(0u64, |acc, machine| match machine.solve() {
+                Some([a, b]) if a <= 100 && b <= 100 => add_tokens(acc, [a, b]),
+                _ => Ok(acc),
+            })
+    }
+
+    fn part2(machines: &Self::Input) -> Result<u64> {
+        machines
+            .iter()
+            .map(
+                |&Machine {
+                     coefficients,
+                     constants,
+                 }| Machine {
+                    coefficients,
+                    constants: [constants[0] + 10000000000000, constants[1] + 10000000000000],
+                },
+            )
+            .try_fold(0u64, |acc, machine| match machine.solve() {
+                None => Ok(acc),
+                Some(presses) => add_tokens(acc, presses),
+            })
+    }
+}
+
+/// Adds the tokens for pressing A and B `presses` times: 3 for A and 1 for B.
+fn add_tokens(total: u64, [a, b]: [i64; 2]) -> Result<u64> {
+    (3 * a as u64 + b as u64)
+        .checked_add(total)
+        .context("total tokens overflow")
+}
+
+impl Streaming for Day13 {
+    const RECORDS: Records = Records::Blocks;
+    type Totals = (u64, u64);
+
+    fn add(totals: &mut (u64, u64), record: &str) -> Result<()> {
+        add_record::<Day13>(totals, record)
+    }
+
+    fn answers(totals: (u64, u64)) -> Result<(u64, u64)> {
+        Ok(totals)
+    }
+}
@@ -0,0 +1,239 @@
+use crate::stream::{Records, Streaming};
+use crate::{cancel, parse_coord, progress, value, Coord, Solution};
+use anyhow::*;
+use log::trace;
+use nom::bytes::complete::tag;
+use nom::character::complete::newline;
+use nom::combinator::opt;
+use nom::multi::separated_list1;
+use nom::sequence::{preceded, terminated};
+use nom::IResult;
+use std::collections::HashSet;
+use std::fmt::{Debug, Formatter};
+
+pub struct Day14;
+
+#[derive(Clone)]
+pub struct Robot {
+    pub position: Coord,
+    pub velocity: Coord,
+}
+
+impl Robot {
+    /// Moves `by` seconds ahead, wrapping around the map edges.
+    pub fn advance_in(&mut self, by: i32, map_size: &Coord) {
+        self.position = (self.position + self.velocity * by + *map_size * by) % *map_size;
+    }
+}
+
+pub fn parse_robot(input: &str) -> IResult<&str, Robot> {
+    let (rem, _) = tag("p=")(input)?;
+    let (rem, position) = parse_coord(rem)?;
+    let (rem, _) = tag(" v=")(rem)?;
+    let (rem, velocity) = parse_coord(rem)?;
+
+    IResult::Ok((rem, Robot { position, velocity }))
+}
+
+/// The real inputs' map size. The puzzle doesn't give it in the input, so
+/// examples on other maps start with a `size=11,7` line.
+pub const MAP_SIZE: Coord = Coord { x: 101, y: 103 };
+
+/// The widest or tallest map accepted, far past the real one.
+pub const MAX_MAP_SIZE: i32 = 10_000;
+
+fn parse_size(input: &str) -> IResult<&str, Coord> {
+    preceded(tag("size="), parse_coord)(input)
+}
+
+fn check_map_size(map_size: Coord) -> Result<Coord> {
+    ensure!(
+        (1..=MAX_MAP_SIZE).contains(&map_size.x) && (1..=MAX_MAP_SIZE).contains(&map_size.y),
+        "map size must be between 1 and {MAX_MAP_SIZE}"
+    );
+    Ok(map_size)
+}
+
+/// Rejects robots [`Robot::advance_in`] can't keep on the map.
+fn check_robot(Robot { position, velocity }: &Robot, map_size: Coord) -> Result<()> {
+    ensure!(
+        position.in_rect(Coord::zero(), map_size - Coord { x: 1, y: 1 }),
+        "robot at {position:?} is off the map"
+    );
+    ensure!(
+        velocity.x.abs() < map_size.x && velocity.y.abs() < map_size.y,
+        "robot velocity {velocity:?} crosses the whole map in one second"
+    );
+    Ok(())
+}
+
+/// The quadrant `robot` is in after 100 seconds, numbered left to right and
+/// top to bottom, or `None` on the middle row or column.
+pub fn quadrant_after_100(robot: &Robot, map_size: Coord) -> Option<usize> {
+    let mut robot = robot.clone();
+    robot.advance_in(100, &map_size);
+    let (Coord { x, y }, middle) = (robot.position, map_size / 2);
+    if x == middle.x || y == middle.y {
+        return None;
+    }
+    Some(usize::from(x > middle.x) + 2 * usize::from(y > middle.y))
+}
+
+pub struct Bathroom {
+    pub robots: Vec<Robot>,
+    pub map_size: Coord,
+}
+
+impl Solution for Day14 {
+    type Input = Bathroom;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rest, map_size) =
+            opt(terminated(parse_size, newline))(input).map_err(|e| e.to_owned())?;
+        let map_size = check_map_size(map_size.unwrap_or(MAP_SIZE))?;
+        let (rest, robots) =
+            separated_list1(newline, parse_robot)(rest).map_err(|e| e.to_owned())?;
+        ensure!(
+            matches!(rest, "" | "\n"),
+            "unexpected {rest:?} after the last robot"
+        );
+        for robot in &robots {
+            check_robot(robot, map_size)?;
+        }
+
+        Ok(Bathroom { robots, map_size })
+    }
+
+    fn part1(Bathroom { robots, map_size }: &Self::Input) -> Result<u64> {
+        let mut quadrants = [0u64; 4];
+        robots
+            .iter()
+            .filter_map(|robot| quadrant_after_100(robot, *map_size))
+            .for_each(|quadrant| quadrants[quadrant] += 1);
+
+        Ok(quadrants.iter().product())
+    }
+
+    fn part2(Bathroom { robots, map_size }: &Self::Input) -> Result<u64> {
+        let map_size = *map_size;
+        let mut robots = robots.clone();
+
+        let mut i = 1;
+        loop {
+            cancel::check()?;
+            progress::tick();
+            let mut robot_map: RobotSet = RobotSet(map_size, HashSet::new());
+            robots.iter_mut().for_each(|r| {
+                r.advance_in(1, &map_size);
+                robot_map.1.insert(r.position);
+            });
+            let (x_hist, y_hist) = robot_map.histograms();
+
+            if x_hist.iter().max().unwrap_or(&0) > &20 && y_hist.iter().max().unwrap_or(&0) > &20 {
+                trace!("robots after {i} seconds:\n{robot_map:?}");
+                break;
+            }
+            i += 1;
+        }
+
+        value!(seconds = i);
+        Ok(i)
+    }
+}
+
+/// Part 1's robots per quadrant, counted as they stream in, and the robots
+/// themselves for part 2, which moves them all together.
+#[derive(Default)]
+pub struct Quadrants {
+    map_size: Option<Coord>,
+    counts: [u64; 4],
+    robots: Vec<Robot>,
+}
+
+impl Streaming for Day14 {
+    const RECORDS: Records = Records::Lines;
+    type Totals = Quadrants;
+
+    fn add(totals: &mut Quadrants, record: &str) -> Result<()> {
+        if let Result::Ok(("", map_size)) = parse_size(record) {
+            ensure!(totals.map_size.is_none(), "the size line must come first");
+            totals.map_size = Some(check_map_size(map_size)?);
+            return Ok(());
+        }
+        let map_size = *totals.map_size.get_or_insert(MAP_SIZE);
+        let (rest, robot) = parse_robot(record).map_err(|e| e.to_owned())?;
+        ensure!(rest.is_empty(), "unexpected {rest:?} after robot");
+        check_robot(&robot, map_size)?;
+
+        if let Some(quadrant) = quadrant_after_100(&robot, map_size) {
+            totals.counts[quadrant] += 1;
+        }
+        totals.robots.push(robot);
+        Ok(())
+    }
+
+    fn answers(totals: Quadrants) -> Result<(u64, u64)> {
+        ensure!(!totals.robots.is_empty(), "no robots");
+        let bathroom = Bathroom {
+            robots: totals.robots,
+            map_size: totals.map_size.unwrap_or(MAP_SIZE),
+        };
+        Ok((totals.counts.iter().product(), Day14::part2(&bathroom)?))
+    }
+}
+
+/// Robot positions on a map of the given size.
+pub struct RobotSet(pub Coord, pub HashSet<Coord>);
+
+impl RobotSet {
+    /// Robots per column and per row.
+    pub fn histograms(&self) -> (Vec<u32>, Vec<u32>) {
+        let mut x_hist = vec![0; self.0.x as usize];
+        let mut y_hist = vec![0; self.0.y as usize];
+
+        self.1.iter().for_each(|Coord { x, y }| {
+            x_hist[*x as usize] += 1;
+            y_hist[*y as usize] += 1;
+        });
+
+        (x_hist, y_hist)
+    }
+}
+
+impl Debug for RobotSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let map_size = self.0;
+        let robots = &self.1;
+
+        let mut res = String::with_capacity((map_size.x as usize + 1) * map_size.y as usize);
+
+        (0..map_size.y).for_each(|y| {
+            (0..map_size.x).for_each(|x| {
+                if robots.contains(&Coord { x, y }) {
+                    res.push('#');
+                } else {
+                    res.push('.');
+                }
+            });
+            res.push('\n');
+        });
+
+        f.write_str(&res)
+    }
+}
+
+#[test]
+fn missing_size_header_means_the_real_map() {
+    let input = crate::examples::Example::load(2024, 14, "example")
+        .unwrap()
+        .input;
+    assert_eq!(
+        Day14::parse(&input).unwrap().map_size,
+        Coord { x: 11, y: 7 }
+    );
+
+    let (_, robots) = input.split_once('\n').unwrap();
+    assert_eq!(Day14::parse(robots).unwrap().map_size, MAP_SIZE);
+}
@@ -0,0 +1,338 @@
+use crate::simulation::{Condition, Debuggable, Simulation};
+use crate::{parse_with_coords, recording, Coord, Solution};
+use anyhow::*;
+use itertools::Itertools;
+use log::{log_enabled, trace, Level};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Debug, Formatter};
+
+pub struct Day15;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Obj {
+    Wall,
+    Robot,
+    Crate,
+    LCrate,
+}
+
+impl Solution for Day15 {
+    type Input = (Warehouse, Vec<Coord>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (map, directions) = input
+            .split("\n\n")
+            .take(2)
+            .collect_tuple()
+            .context("missing blank line between map and moves")?;
+        let (map_size, warehouse) = parse_with_coords(map, |c| match c {
+            '#' => Some(Obj::Wall),
+            'O' => Some(Obj::Crate),
+            '@' => Some(Obj::Robot),
+            _ => None,
+        });
+        // A second robot would be an immovable obstacle that pushes never resolve.
+        let robots = warehouse.values().filter(|&o| o == &Obj::Robot).count();
+        ensure!(
+            robots == 1,
+            "expected one robot in the warehouse, found {robots}"
+        );
+        // Walls all round keep the robot and crates from leaving the map.
+        let walled = (0..=map_size.y).all(|y| {
+            (0..=map_size.x).all(|x| {
+                let edge = x == 0 || y == 0 || x == map_size.x || y == map_size.y;
+                !edge || warehouse.get(&Coord { x, y }) == Some(&Obj::Wall)
+            })
+        });
+        ensure!(walled, "the warehouse isn't walled in");
+        let directions = directions
+            .chars()
+            .filter_map(|d| match d {
+                '<' => Some(Coord { x: -1, y: 0 }),
+                '>' => Some(Coord { x: 1, y: 0 }),
+                '^' => Some(Coord { x: 0, y: -1 }),
+                'v' => Some(Coord { x: 0, y: 1 }),
+                _ => None,
+            })
+            .collect();
+
+        Ok((Warehouse(map_size, warehouse), directions))
+    }
+
+    fn part1((warehouse, directions): &Self::Input) -> Result<u64> {
+        run(Moves::new(Floor::new(warehouse, false), directions.clone()))
+    }
+
+    fn part2((warehouse, directions): &Self::Input) -> Result<u64> {
+        run(Moves::new(Floor::new(warehouse, true), directions.clone()))
+    }
+}
+
+impl Debuggable for Day15 {
+    type Simulation = Moves;
+
+    fn simulation((warehouse, directions): &Self::Input, part: u8) -> Result<Moves> {
+        match part {
+            1 | 2 => Ok(Moves::new(
+                Floor::new(warehouse, part == 2),
+                directions.clone(),
+            )),
+            _ => bail!("2024 day 15 has no part {part}"),
+        }
+    }
+
+    fn breakpoints() -> Vec<(&'static str, Condition<Moves>)> {
+        vec![("crate-into-wall", Moves::pushes_crate_into_wall)]
+    }
+}
+
+/// Makes every move, recording a frame after each, and sums the crates' GPS coordinates.
+fn run(mut moves: Moves) -> Result<u64> {
+    recording::frame(|| "start".to_string(), || moves.floor.draw());
+    while !moves.done() {
+        let direction = moves.directions[moves.next];
+        moves.step()?;
+        recording::frame(
+            || caption(moves.next - 1, moves.directions.len(), direction),
+            || moves.floor.draw(),
+        );
+    }
+
+    if log_enabled!(Level::Trace) {
+        trace!("final warehouse: {:?}", moves.floor);
+    }
+
+    let left = if moves.floor.wide {
+        Obj::LCrate
+    } else {
+        Obj::Crate
+    };
+    Ok(moves
+        .floor
+        .warehouse
+        .iter()
+        .fold(0, |acc, (c, obj)| match obj {
+            _ if *obj == left => acc + c.x as u64 + 100 * c.y as u64,
+            _ => acc,
+        }))
+}
+
+/// The warehouse between moves, with the robot kept apart from everything else.
+#[derive(Clone)]
+pub struct Floor {
+    pub map_size: Coord,
+    pub warehouse: HashMap<Coord, Obj>,
+    pub robot: Coord,
+    /// Whether everything is twice as wide, with crates as `LCrate` and `Crate` halves.
+    pub wide: bool,
+}
+
+impl Floor {
+    pub fn new(Warehouse(map_size, warehouse): &Warehouse, wide: bool) -> Floor {
+        let (map_size, mut warehouse) = match wide {
+            false => (*map_size, warehouse.clone()),
+            true => (
+                Coord {
+                    x: map_size.x * 2 + 1,
+                    y: map_size.y,
+                },
+                widen(warehouse),
+            ),
+        };
+        let robot = *warehouse
+            .iter()
+            .find(|&(_, o)| o == &Obj::Robot)
+            .expect("parse checks there's a robot")
+            .0;
+        warehouse.remove(&robot);
+
+        Floor {
+            map_size,
+            warehouse,
+            robot,
+            wide,
+        }
+    }
+
+    /// The crate cells a move would push, in an order they can be moved in,
+    /// or `None` if a wall is in the way.
+    fn plan(&self, direction: Coord) -> Option<VecDeque<Coord>> {
+        let mut next_pos = vec![self.robot + direction];
+        let mut move_crates = VecDeque::new();
+        while let Some(candidate) = next_pos.pop() {
+            if move_crates.contains(&candidate) {
+                continue;
+            }
+            let other_half = match self.warehouse.get(&candidate) {
+                Some(Obj::Wall) => return None,
+                Some(Obj::Crate) if !self.wide => None,
+                Some(Obj::Crate) => Some(Coord { x: -1, y: 0 }),
+                Some(Obj::LCrate) => Some(Coord { x: 1, y: 0 }),
+                _ => continue,
+            };
+            move_crates.push_front(candidate);
+            next_pos.push(candidate + direction);
+            if let Some(offset) = other_half {
+                move_crates.push_front(candidate + offset);
+                next_pos.push(candidate + direction + offset);
+            }
+        }
+        Some(move_crates)
+    }
+
+    fn draw(&self) -> String {
+        draw(self.map_size, &self.warehouse, Some(self.robot), self.wide)
+    }
+
+    /// Moves the robot, pushing any crates in the way, unless a wall stops it.
+    fn push(&mut self, direction: Coord) {
+        let Some(mut move_crates) = self.plan(direction) else {
+            return;
+        };
+        while let Some(m) = move_crates.pop_front() {
+            if self.warehouse.contains_key(&(m + direction)) {
+                move_crates.push_back(m);
+            } else {
+                let cr = self.warehouse.remove(&m).unwrap();
+                self.warehouse.insert(m + direction, cr);
+            }
+        }
+        self.robot = self.robot + direction;
+    }
+}
+
+impl Debug for Floor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Coord { x, y } = self.robot;
+        writeln!(f, "robot at {x},{y}")?;
+        write!(f, "{}", self.draw())
+    }
+}
+
+/// Doubles everything's width for part 2, crates becoming an `LCrate` and a `Crate`.
+fn widen(warehouse: &HashMap<Coord, Obj>) -> HashMap<Coord, Obj> {
+    let mut sorted_warehouse = warehouse.iter().collect_vec();
+    sorted_warehouse.sort_by(|(a, _), (b, _)| a.x.cmp(&b.x).reverse());
+
+    let mut warehouse = HashMap::new();
+    sorted_warehouse.iter().for_each(|(&c, &o)| match o {
+        Obj::Wall => {
+            warehouse.insert(Coord { x: c.x * 2, y: c.y }, Obj::Wall);
+            warehouse.insert(
+                Coord {
+                    x: c.x * 2 + 1,
+                    y: c.y,
+                },
+                Obj::Wall,
+            );
+        }
+        Obj::Crate => {
+            warehouse.insert(Coord { x: c.x * 2, y: c.y }, Obj::LCrate);
+            warehouse.insert(
+                Coord {
+                    x: c.x * 2 + 1,
+                    y: c.y,
+                },
+                Obj::Crate,
+            );
+        }
+        Obj::Robot => {
+            warehouse.insert(Coord { x: c.x * 2, y: c.y }, Obj::Robot);
+        }
+        _ => {}
+    });
+    warehouse
+}
+
+/// The robot's moves one at a time.
+pub struct Moves {
+    pub floor: Floor,
+    directions: Vec<Coord>,
+    /// Index of the next move to make.
+    next: usize,
+}
+
+impl Moves {
+    pub fn new(floor: Floor, directions: Vec<Coord>) -> Moves {
+        Moves {
+            floor,
+            directions,
+            next: 0,
+        }
+    }
+
+    /// Whether the next move would push a crate into a wall, leaving everything where it is.
+    pub fn pushes_crate_into_wall(&self) -> bool {
+        let Some(&direction) = self.directions.get(self.next) else {
+            return false;
+        };
+        let neighbour = self.floor.warehouse.get(&(self.floor.robot + direction));
+        matches!(neighbour, Some(Obj::Crate | Obj::LCrate)) && self.floor.plan(direction).is_none()
+    }
+}
+
+impl Simulation for Moves {
+    type State = Floor;
+
+    fn state(&self) -> &Floor {
+        &self.floor
+    }
+
+    fn step(&mut self) -> Result<()> {
+        self.floor.push(self.directions[self.next]);
+        self.next += 1;
+        Ok(())
+    }
+
+    fn done(&self) -> bool {
+        self.next == self.directions.len()
+    }
+}
+
+/// The move just made, for a recording's caption.
+fn caption(i: usize, moves: usize, direction: Coord) -> String {
+    let arrow = match (direction.x, direction.y) {
+        (-1, 0) => '<',
+        (1, 0) => '>',
+        (0, -1) => '^',
+        _ => 'v',
+    };
+    format!("move {}/{moves} {arrow}", i + 1)
+}
+
+/// Draws the warehouse as the puzzle does, with `robot` as `@` and crates as
+/// `O`, or as `[]` halves when `wide`.
+fn draw(
+    map_size: Coord,
+    warehouse: &HashMap<Coord, Obj>,
+    robot: Option<Coord>,
+    wide: bool,
+) -> String {
+    (0..=map_size.y)
+        .map(|y| {
+            (0..=map_size.x)
+                .map(|x| match warehouse.get(&Coord { x, y }) {
+                    _ if robot == Some(Coord { x, y }) => '@',
+                    Some(Obj::Wall) => '#',
+                    Some(Obj::Crate) if wide => ']',
+                    Some(Obj::Crate) => 'O',
+                    Some(Obj::LCrate) => '[',
+                    Some(Obj::Robot) => '@',
+                    None => '.',
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+/// The map size and everything that isn't empty floor.
+pub struct Warehouse(pub Coord, pub HashMap<Coord, Obj>);
+
+impl Debug for Warehouse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "\n{}", draw(self.0, &self.1, None, true))
+    }
+}
@@ -0,0 +1,169 @@
+use aoc::answers::input_hash;
+use aoc::examples::{Example, ANY_ANSWER};
+use aoc::gen;
+use aoc::recording::{self, Recording, Sink};
+use aoc::registry;
+use aoc::report::Status;
+use aoc::rng::Rng;
+use aoc::runner::{solve_with, SolveOptions};
+use aoc::simulation::{Debugger, Stop};
+use aoc::y2024::day02::{check_is_safe, check_is_safe_dampened, parse_levels};
+use aoc::y2024::day06::{Day06, Patrol};
+use aoc::y2024::day13::Machine;
+use aoc::y2024::day15::{Day15, Floor, Moves};
+use aoc::Coord;
+use aoc::Solution;
+use std::borrow::Cow;
+use std::time::Duration;
+
+#[test]
+fn day02_helpers() {
+    let levels = parse_levels("1 3 2 4 5").unwrap();
+    assert!(!check_is_safe(&levels));
+    assert!(check_is_safe_dampened(&levels));
+}
+
+#[test]
+fn day13_machine_solve() {
+    let machine = Machine {
+        coefficients: [[94, 34], [22, 67]],
+        constants: [8400, 5400],
+    };
+    assert_eq!(machine.solve(), Some([80, 40]));
+}
+
+#[test]
+fn day06_guard_in_a_loop_times_out() {
+    let options = SolveOptions {
+        timeout: Some(Duration::from_millis(100)),
+        show_progress: false,
+    };
+    let day = registry::find(2024, 6).unwrap();
+    let records = solve_with(day, ".#..\n...#\n#^..\n..#.\n", options);
+    assert!(records
+        .iter()
+        .all(|record| record.status == Status::TimedOut));
+}
+
+#[test]
+fn day06_records_a_frame_per_step() {
+    let input = Example::load(2024, 6, "example").unwrap().input;
+    let parsed = Day06::parse(&input).unwrap();
+    let sink = Sink::Recording(Recording::new("day 6"));
+    let (answer, sink) = recording::record(sink, || Day06::part1(&parsed));
+    let Sink::Recording(recording) = sink else {
+        unreachable!()
+    };
+    assert_eq!(answer.unwrap(), 41);
+    // The last frame is the guard stepping off the map, past every cell it visited.
+    let last = recording.len() - 1;
+    assert_eq!(recording.frame(last).count('X'), 41);
+    assert_eq!(recording.caption(last), "41 cells visited");
+}
+
+#[test]
+fn day06_debugger_stops_when_the_guard_loops() {
+    let input = Example::load(2024, 6, "example").unwrap().input;
+    let (map_size, map) = Day06::parse(&input).unwrap();
+
+    let mut debugger = Debugger::new(Patrol::new(Cow::Borrowed(&map), map_size).unwrap());
+    debugger.break_when("revisit", Patrol::revisiting);
+    assert_eq!(debugger.run(None).unwrap(), Stop::Done);
+    assert_eq!(debugger.simulation().positions().len(), 41);
+
+    let obstruction = Coord { x: 3, y: 6 };
+    let mut debugger = Debugger::new(
+        Patrol::new(Cow::Borrowed(&map), map_size)
+            .unwrap()
+            .with_obstruction(obstruction),
+    );
+    debugger.break_when("revisit", Patrol::revisiting);
+    debugger.keep_history(4);
+    assert_eq!(
+        debugger.run(None).unwrap(),
+        Stop::Breakpoint("revisit".to_string())
+    );
+    let guard = *debugger.state();
+    assert!(debugger
+        .simulation()
+        .visited(guard.position, guard.direction));
+    assert_eq!(debugger.history().count(), 4);
+    assert_eq!(debugger.history().last(), Some(&(debugger.steps(), guard)));
+}
+
+#[test]
+fn day15_debugger_stops_before_a_crate_hits_a_wall() {
+    let input = Example::load(2024, 15, "small").unwrap().input;
+    let (warehouse, directions) = Day15::parse(&input).unwrap();
+    let mut debugger = Debugger::new(Moves::new(Floor::new(&warehouse, false), directions));
+    debugger.break_when("crate-into-wall", Moves::pushes_crate_into_wall);
+
+    // `<^^>>` leaves the robot pushing two crates against the east wall.
+    assert_eq!(
+        debugger.run(None).unwrap(),
+        Stop::Breakpoint("crate-into-wall".to_string())
+    );
+    assert_eq!(debugger.steps(), 5);
+    assert_eq!(debugger.state().robot, Coord { x: 4, y: 1 });
+
+    // The same through the registry, as `aoc debug` does it.
+    let day = registry::find(2024, 15).unwrap();
+    let parsed = day.parse(&input).unwrap();
+    let mut session = day
+        .debug(&parsed, 1, &["crate-into-wall".to_string()])
+        .unwrap();
+    session.keep_history(2);
+    assert!(matches!(session.run(None).unwrap(), Stop::Breakpoint(_)));
+    assert!(session.state().contains('@'));
+    assert_eq!(session.history().len(), 2);
+    assert!(day.debug(&parsed, 2, &["nope".to_string()]).is_err());
+}
+
+#[test]
+fn inputs_are_normalised() {
+    let check = |day: u8, example: &str| {
+        let day = registry::find(2024, day).unwrap();
+        let input = Example::load(day.year, day.day, example).unwrap().input;
+        let messy = format!("\u{feff}{}\r\n\r\n", input.replace('\n', "\r\n"));
+        let (clean, messy) = (day.parse(&input).unwrap(), day.parse(&messy).unwrap());
+        assert_eq!(day.part1(&clean).unwrap(), day.part1(&messy).unwrap());
+        assert_eq!(day.part2(&clean).unwrap(), day.part2(&messy).unwrap());
+    };
+    check(9, "example");
+    check(13, "example");
+    check(15, "small");
+}
+
+#[test]
+fn trailing_garbage_is_rejected() {
+    for day in [13, 14] {
+        let day = registry::find(2024, day).unwrap();
+        let input = Example::load(day.year, day.day, "example").unwrap().input;
+        assert!(day.parse(&format!("{input}\n")).is_ok());
+        assert!(day.parse(&format!("{input}\nnot a puzzle line")).is_err());
+    }
+}
+
+#[test]
+fn streaming_matches_whole_input() {
+    for day in registry::days().filter(|day| day.can_stream()) {
+        // Examples without both answers may never finish the other part, e.g.
+        // day 14's, and fuzz cases, which accept any answer, needn't even parse.
+        let examples = Example::load_day(day.year, day.day)
+            .unwrap()
+            .into_iter()
+            .filter(|example| {
+                example.answers.len() == 2 && !example.answers.values().any(|a| a == ANY_ANSWER)
+            })
+            .map(|example| example.input);
+        let generated = gen::generate(day.year, day.day, 20, &mut Rng::new(1)).unwrap();
+
+        for input in examples.chain([generated]) {
+            let parsed = day.parse(&input).unwrap();
+            let streamed = day.stream(&mut input.as_bytes()).unwrap();
+            assert_eq!(streamed.answers[0], day.part1(&parsed).unwrap(), "{day}");
+            assert_eq!(streamed.answers[1], day.part2(&parsed).unwrap(), "{day}");
+            assert_eq!(streamed.input_hash, input_hash(&input));
+        }
+    }
+}
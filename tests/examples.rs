@@ -0,0 +1,39 @@
+use aoc::examples::{Example, ANY_ANSWER};
+use aoc::registry;
+use aoc::report::Status;
+use aoc::runner::{solve_with, SolveOptions};
+use std::time::Duration;
+
+fn check(year: u16, day: u8, name: &str, part: u8) {
+    let example = Example::load(year, day, name).unwrap();
+    let solution = registry::find(year, day).unwrap();
+
+    if example.answers[&part] == ANY_ANSWER {
+        let options = SolveOptions {
+            timeout: Some(Duration::from_secs(10)),
+            show_progress: false,
+        };
+        let record = &solve_with(solution, &example.input, options)[part as usize - 1];
+        assert_ne!(
+            record.status,
+            Status::Panicked,
+            "{year} day {day} example {name:?} part {part}: {:?}",
+            record.error
+        );
+        return;
+    }
+
+    let parsed = solution.parse(&example.input).unwrap();
+    for implementation in solution.implementations() {
+        if implementation.part == part {
+            assert_eq!(
+                implementation.solve(&parsed).unwrap(),
+                example.answers[&part],
+                "{year} day {day} example {name:?} part {part} ({})",
+                implementation.name
+            );
+        }
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
@@ -0,0 +1,101 @@
+use aoc::y2024::day07::{self, Day07};
+use aoc::y2024::day09::{self, Day09};
+use aoc::y2024::day11::{self, count_stones};
+use aoc::y2024::day13::{self, Day13, Machine};
+use aoc::Solution;
+use proptest::prelude::*;
+
+/// A calibration whose target is reachable about half the time.
+fn calibration() -> impl Strategy<Value = (u64, Vec<u64>)> {
+    (
+        prop::collection::vec(1u64..=99, 1..=5),
+        prop::collection::vec(0u8..3, 4),
+        any::<bool>(),
+        1u64..10_000,
+    )
+        .prop_map(|(parts, ops, reachable, random)| {
+            let target = if reachable {
+                parts[1..]
+                    .iter()
+                    .zip(&ops)
+                    .fold(parts[0], |acc, (&part, op)| match op {
+                        0 => acc + part,
+                        1 => acc * part,
+                        _ => format!("{acc}{part}").parse().unwrap(),
+                    })
+            } else {
+                random
+            };
+            (target, parts)
+        })
+}
+
+/// A machine whose prize is usually, but not always, reachable within 100 presses.
+fn machine() -> impl Strategy<Value = Machine> {
+    (
+        [[1i64..=30, 1i64..=30], [1i64..=30, 1i64..=30]],
+        [
+            prop_oneof![0i64..=120, 98i64..=102],
+            prop_oneof![0i64..=120, 98i64..=102],
+        ],
+        [-3i64..=3, -3i64..=3],
+    )
+        .prop_map(|(coefficients, [a, b], [dx, dy])| Machine {
+            coefficients,
+            constants: [
+                a * coefficients[0][0] + b * coefficients[1][0] + dx,
+                a * coefficients[0][1] + b * coefficients[1][1] + dy,
+            ],
+        })
+        .prop_filter("buttons must not be parallel", |m| m.determinant() != 0)
+}
+
+proptest! {
+    #[test]
+    fn day07_matches_reference(calibrations in prop::collection::vec(calibration(), 1..8)) {
+        prop_assert_eq!(
+            Day07::part1(&calibrations).unwrap(),
+            day07::reference::total(&calibrations, false)
+        );
+        prop_assert_eq!(
+            Day07::part2(&calibrations).unwrap(),
+            day07::reference::total(&calibrations, true)
+        );
+    }
+
+    #[test]
+    fn day09_matches_reference(
+        disk_map in prop::collection::vec(0u32..=9, 1..24)
+            .prop_map(|map| map
+                .into_iter()
+                .enumerate()
+                .map(|(i, len)| if i % 2 == 0 { len.max(1) } else { len })
+                .collect::<Vec<_>>())
+    ) {
+        prop_assert_eq!(
+            Day09::part1(&disk_map).unwrap(),
+            day09::reference::compact_blocks(&disk_map)
+        );
+        prop_assert_eq!(
+            Day09::part2(&disk_map).unwrap(),
+            day09::reference::compact_files(&disk_map)
+        );
+    }
+
+    #[test]
+    fn day11_matches_reference(
+        stones in prop::collection::vec(0u64..10_000, 1..5),
+        blinks in 0usize..16,
+    ) {
+        prop_assert_eq!(
+            count_stones(&stones, blinks),
+            day11::reference::count_stones(&stones, blinks)
+        );
+    }
+
+    #[test]
+    fn day13_matches_reference(machine in machine()) {
+        let expected = day13::reference::cost(&machine, 100).unwrap_or(0);
+        prop_assert_eq!(Day13::part1(&vec![machine]).unwrap() as i64, expected);
+    }
+}